@@ -0,0 +1,88 @@
+// Writes a generated input for one day, e.g.
+// `cargo run -p aoc --example generate -- day_three 1000 2022`
+// writes 1000 groups of rucksacks to `day_three/generated.txt`
+
+#[path = "../../day_eight/src/generator.rs"]
+mod day_eight;
+#[path = "../../day_five/src/generator.rs"]
+mod day_five;
+#[path = "../../day_four/src/generator.rs"]
+mod day_four;
+#[path = "../../day_one/src/generator.rs"]
+mod day_one;
+#[path = "../../day_seven/src/generator.rs"]
+mod day_seven;
+#[path = "../../day_six/src/generator.rs"]
+mod day_six;
+#[path = "../../day_three/src/generator.rs"]
+mod day_three;
+#[path = "../../day_two/src/generator.rs"]
+mod day_two;
+
+use std::{env, fs, path::Path, process};
+
+// A day's generator, taking the size of the input and a seed. The same size
+// and seed always give the same input.
+type Generator = fn(usize, u64) -> String;
+
+const GENERATORS: &[(&str, Generator)] = &[
+    ("day_one", day_one::generate),
+    ("day_two", day_two::generate),
+    ("day_three", day_three::generate),
+    ("day_four", day_four::generate),
+    ("day_five", day_five::generate),
+    ("day_six", day_six::generate),
+    ("day_seven", day_seven::generate),
+    ("day_eight", day_eight::generate),
+];
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    if args.len() < 2 || args.len() > 4 {
+        eprintln!("usage: generate <day> <size> [seed] [output]");
+        process::exit(2);
+    }
+
+    let day = &args[0];
+    let generate = GENERATORS
+        .iter()
+        .find(|(name, _)| name == day)
+        .map(|(_, generate)| generate)
+        .unwrap_or_else(|| panic!("Unknown day: {}", day));
+    let size = args[1]
+        .parse::<usize>()
+        .unwrap_or_else(|err| panic!("Invalid size: {}", err));
+    let seed = args
+        .get(2)
+        .map(|seed| seed.parse::<u64>())
+        .transpose()
+        .unwrap_or_else(|err| panic!("Invalid seed: {}", err))
+        .unwrap_or(2022);
+    let output = match args.get(3) {
+        Some(output) => Path::new(output).to_path_buf(),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join(day)
+            .join("generated.txt"),
+    };
+
+    fs::write(&output, generate(size, seed))
+        .unwrap_or_else(|err| panic!("Unable to write {}: {}", output.display(), err));
+
+    println!("created {}", output.display());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generators_are_deterministic() {
+        for (day, generate) in GENERATORS {
+            assert_eq!(generate(100, 7), generate(100, 7), "{}", day);
+            assert_ne!(generate(100, 7), generate(100, 8), "{}", day);
+        }
+    }
+}
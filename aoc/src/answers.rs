@@ -0,0 +1,120 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AnswersError {
+    Toml(String),
+    // Every top level key names an input file and must hold a table
+    NotATable(String),
+    InvalidAnswer { input: String, part: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Toml(err) => write!(f, "{}", err),
+            AnswersError::NotATable(input) => write!(f, "{}: expected a table of answers", input),
+            AnswersError::InvalidAnswer { input, part } => {
+                write!(f, "{}: {} must be a string or an integer", input, part)
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+// The confirmed answers for one input file. A part left out hasn't been
+// confirmed yet, so it isn't checked.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+fn answer(input: &str, table: &toml::Table, part: &str) -> Result<Option<String>, AnswersError> {
+    match table.get(part) {
+        None => Ok(None),
+        Some(toml::Value::String(answer)) => Ok(Some(answer.to_owned())),
+        Some(toml::Value::Integer(answer)) => Ok(Some(answer.to_string())),
+        Some(_) => Err(AnswersError::InvalidAnswer {
+            input: input.to_owned(),
+            part: part.to_owned(),
+        }),
+    }
+}
+
+// Reads an `answers.toml` written as
+//
+//     ["input.txt"]
+//     part_one = "69528"
+//     part_two = 206152
+//
+// with one table per input file
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, AnswersError> {
+    let tables = text
+        .parse::<toml::Table>()
+        .map_err(|err| AnswersError::Toml(err.to_string()))?;
+
+    tables
+        .iter()
+        .map(|(input, table)| {
+            let table = table
+                .as_table()
+                .ok_or_else(|| AnswersError::NotATable(input.to_owned()))?;
+
+            Ok(Expected {
+                input: input.to_owned(),
+                part_one: answer(input, table, "part_one")?,
+                part_two: answer(input, table, "part_two")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_string_and_integer_answers() {
+        let answers = parse_answers(
+            "[\"input.txt\"]\npart_one = \"ZRLJGSCTR\"\npart_two = 42\n\n[\"sample.txt\"]\npart_one = 7\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers,
+            vec![
+                Expected {
+                    input: "input.txt".to_string(),
+                    part_one: Some("ZRLJGSCTR".to_string()),
+                    part_two: Some("42".to_string()),
+                },
+                Expected {
+                    input: "sample.txt".to_string(),
+                    part_one: Some("7".to_string()),
+                    part_two: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        assert_eq!(
+            parse_answers("part_one = 1"),
+            Err(AnswersError::NotATable("part_one".to_string()))
+        );
+        assert_eq!(
+            parse_answers("[\"input.txt\"]\npart_one = [1]"),
+            Err(AnswersError::InvalidAnswer {
+                input: "input.txt".to_string(),
+                part: "part_one".to_string()
+            })
+        );
+        assert!(matches!(
+            parse_answers("[\"input.txt\""),
+            Err(AnswersError::Toml(_))
+        ));
+    }
+}
@@ -0,0 +1,14 @@
+use common::Solution;
+
+// Every day, by the name of its crate directory. `aoc new` adds new days
+// before the closing bracket.
+pub const DAYS: &[(&str, &dyn Solution)] = &[
+    ("day_one", &day_one::DayOne),
+    ("day_two", &day_two::DayTwo),
+    ("day_three", &day_three::DayThree),
+    ("day_four", &day_four::DayFour),
+    ("day_five", &day_five::DayFive),
+    ("day_six", &day_six::DaySix),
+    ("day_seven", &day_seven::DaySeven),
+    ("day_eight", &day_eight::DayEight),
+];
@@ -0,0 +1,115 @@
+mod answers;
+mod days;
+mod scaffold;
+mod verify;
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use common::Solution;
+
+use answers::parse_answers;
+use days::DAYS;
+use scaffold::{scaffold, NewDay};
+use verify::verify;
+
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn day_dir(day: &str) -> PathBuf {
+    workspace_dir().join(day)
+}
+
+// Checks every input listed in the day's `answers.toml`, returning whether
+// they all matched
+fn verify_day(day: &str, solution: &dyn Solution) -> bool {
+    let dir = day_dir(day);
+
+    let answers = match fs::read_to_string(dir.join("answers.toml")) {
+        Ok(answers) => answers,
+        Err(err) => {
+            println!("{}: unable to read answers.toml: {}", day, err);
+            return false;
+        }
+    };
+
+    let answers = match parse_answers(&answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            println!("{}: invalid answers.toml: {}", day, err);
+            return false;
+        }
+    };
+
+    let mut passed = true;
+
+    for expected in answers {
+        let input = match fs::read_to_string(dir.join(&expected.input)) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{} {}: unable to read input: {}", day, expected.input, err);
+                passed = false;
+                continue;
+            }
+        };
+
+        for check in verify(solution, &expected, &input) {
+            passed &= check.passed();
+            println!("{} {}", day, check);
+        }
+    }
+
+    passed
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    match args.first().map(String::as_str) {
+        // `verify [day]` checks the answers of every day, or just the given one
+        Some("verify") => {
+            let days = match args.get(1) {
+                Some(day) => DAYS
+                    .iter()
+                    .filter(|(name, _)| name == day)
+                    .collect::<Vec<_>>(),
+                None => DAYS.iter().collect(),
+            };
+
+            if days.is_empty() {
+                panic!("Unknown day: {}", args[1]);
+            }
+
+            let mut passed = true;
+
+            for (day, solution) in days {
+                passed &= verify_day(day, *solution);
+            }
+
+            if !passed {
+                process::exit(1);
+            }
+        }
+        // `new <day>` creates the crate for day <day> and adds it to the
+        // workspace and to `DAYS`
+        Some("new") => {
+            let day = args.get(1).expect("Expected a day");
+            let day = NewDay::parse(day).unwrap_or_else(|err| panic!("Invalid day: {}", err));
+
+            let created = scaffold(workspace_dir(), &day)
+                .unwrap_or_else(|err| panic!("Unable to create {}: {}", day.name, err));
+
+            for path in created {
+                println!("created {}", path.display());
+            }
+        }
+        _ => {
+            eprintln!("usage: aoc verify [day] | aoc new <day>");
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,412 @@
+use std::{
+    error::Error,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+const NUMBERS: [&str; 25] = [
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "twenty",
+    "twenty_one",
+    "twenty_two",
+    "twenty_three",
+    "twenty_four",
+    "twenty_five",
+];
+
+// Each template's path in a new crate, with `__CRATE__` in the path replaced
+// like everywhere else
+const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    (
+        "answers.toml",
+        include_str!("../templates/answers.toml.tmpl"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    (
+        "benches/__CRATE__.rs",
+        include_str!("../templates/bench.rs.tmpl"),
+    ),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScaffoldError {
+    InvalidDay(String),
+    AlreadyExists(PathBuf),
+    // The file is missing the list that new days are added to
+    NoRegistry(PathBuf),
+    Io { path: PathBuf, message: String },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "{:?} is not a day from 1 to 25", day),
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::NoRegistry(path) => {
+                write!(f, "{}: unable to find where to add the day", path.display())
+            }
+            ScaffoldError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+fn io_error(path: &Path, err: std::io::Error) -> ScaffoldError {
+    ScaffoldError::Io {
+        path: path.to_owned(),
+        message: err.to_string(),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NewDay {
+    pub day: u8,
+    // `day_nine`, the crate and its directory
+    pub name: String,
+    // `DayNine`, the crate's `Solution`
+    pub solution: String,
+}
+
+impl NewDay {
+    pub fn parse(day: &str) -> Result<Self, ScaffoldError> {
+        let number = day
+            .parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| ScaffoldError::InvalidDay(day.to_owned()))?;
+
+        let words = NUMBERS[number as usize - 1];
+        let solution = words
+            .split('_')
+            .map(|word| word[..1].to_uppercase() + &word[1..])
+            .collect::<String>();
+
+        Ok(Self {
+            day: number,
+            name: format!("day_{}", words),
+            solution: format!("Day{}", solution),
+        })
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("__CRATE__", &self.name)
+            .replace("__STRUCT__", &self.solution)
+            .replace("__DAY__", &self.day.to_string())
+    }
+}
+
+// Adds `line` as the last line of the block that starts at the line `start`
+// and runs up to the first line matching `end`
+fn insert_into(text: &str, start: &str, end: impl Fn(&str) -> bool, line: &str) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<&str>>();
+
+    let start = lines.iter().position(|l| l.trim() == start)?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| end(l.trim()))
+        .map(|i| start + 1 + i)
+        .unwrap_or(lines.len());
+
+    lines.insert(end, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+// A workspace file with the text it will have once the day is registered
+struct Edit {
+    path: PathBuf,
+    original: String,
+    updated: String,
+}
+
+fn edit(path: &Path, update: impl FnOnce(&str) -> Option<String>) -> Result<Edit, ScaffoldError> {
+    let original = fs::read_to_string(path).map_err(|err| io_error(path, err))?;
+    let updated = update(&original).ok_or_else(|| ScaffoldError::NoRegistry(path.to_owned()))?;
+
+    Ok(Edit {
+        path: path.to_owned(),
+        original,
+        updated,
+    })
+}
+
+// The edits adding the day to the workspace's members, and to the runner's
+// dependencies and `DAYS`
+fn register(root: &Path, day: &NewDay) -> Result<Vec<Edit>, ScaffoldError> {
+    Ok(vec![
+        edit(&root.join("Cargo.toml"), |text| {
+            insert_into(
+                text,
+                "members = [",
+                |l| l == "]",
+                &format!("    \"{}\",", day.name),
+            )
+        })?,
+        edit(&root.join("aoc/Cargo.toml"), |text| {
+            insert_into(
+                text,
+                "[dependencies]",
+                |l| l.is_empty() || l.starts_with('['),
+                &format!("{} = {{ path = \"../{}\" }}", day.name, day.name),
+            )
+        })?,
+        edit(&root.join("aoc/src/days.rs"), |text| {
+            insert_into(
+                text,
+                "pub const DAYS: &[(&str, &dyn Solution)] = &[",
+                |l| l == "];",
+                &format!("    (\"{}\", &{}::{}),", day.name, day.name, day.solution),
+            )
+        })?,
+    ])
+}
+
+// Writes every edit, or puts back the files already written if one fails
+fn apply(edits: &[Edit]) -> Result<(), ScaffoldError> {
+    for (i, edit) in edits.iter().enumerate() {
+        if let Err(err) = fs::write(&edit.path, &edit.updated) {
+            for written in &edits[..i] {
+                let _ = fs::write(&written.path, &written.original);
+            }
+
+            return Err(io_error(&edit.path, err));
+        }
+    }
+
+    Ok(())
+}
+
+fn create(dir: &Path, day: &NewDay) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let mut created = vec![];
+
+    for (path, template) in TEMPLATES {
+        let path = dir.join(day.render(path));
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| io_error(parent, err))?;
+        }
+
+        fs::write(&path, day.render(template)).map_err(|err| io_error(&path, err))?;
+        created.push(path);
+    }
+
+    Ok(created)
+}
+
+// Creates the day's crate in the workspace at `root` and registers it,
+// returning the files created. Every edit is worked out before anything is
+// written, and a failure part way leaves the workspace as it was.
+pub fn scaffold(root: &Path, day: &NewDay) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let dir = root.join(&day.name);
+
+    if dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(dir));
+    }
+
+    let edits = register(root, day)?;
+
+    let created = create(&dir, day).and_then(|created| apply(&edits).map(|_| created));
+
+    if created.is_err() {
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    created
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process::Command};
+
+    #[test]
+    fn names_days_in_words() {
+        assert_eq!(
+            NewDay::parse("9"),
+            Ok(NewDay {
+                day: 9,
+                name: "day_nine".to_string(),
+                solution: "DayNine".to_string(),
+            })
+        );
+        assert_eq!(NewDay::parse("25").unwrap().solution, "DayTwentyFive");
+        assert_eq!(
+            NewDay::parse("26"),
+            Err(ScaffoldError::InvalidDay("26".to_string()))
+        );
+        assert_eq!(
+            NewDay::parse("nine"),
+            Err(ScaffoldError::InvalidDay("nine".to_string()))
+        );
+    }
+
+    #[test]
+    fn inserts_at_the_end_of_a_block() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n]\n\n[workspace.dependencies]\n";
+
+        assert_eq!(
+            insert_into(manifest, "members = [", |l| l == "]", "    \"day_nine\","),
+            Some(
+                "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_nine\",\n]\n\n[workspace.dependencies]\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            insert_into(
+                "[dependencies]\ntoml = \"0.8\"",
+                "[dependencies]",
+                str::is_empty,
+                "a = 1"
+            ),
+            Some("[dependencies]\ntoml = \"0.8\"\na = 1\n".to_string())
+        );
+        assert_eq!(
+            insert_into("", "[dependencies]", str::is_empty, "a = 1"),
+            None
+        );
+    }
+
+    // A workspace with just a runner, depending on this workspace's `common`
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let common = Path::new(env!("CARGO_MANIFEST_DIR")).join("../common");
+
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            format!(
+                "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n]\n\n\
+                 [workspace.dependencies]\ncommon = {{ path = {:?} }}\ncriterion = \"0.5\"\n",
+                common.canonicalize().unwrap()
+            ),
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[package]\nname = \"aoc\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\ncommon = { workspace = true }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/main.rs"),
+            "mod days;\n\nfn main() {\n    println!(\"{}\", days::DAYS.len());\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "use common::Solution;\n\npub const DAYS: &[(&str, &dyn Solution)] = &[\n];\n",
+        )
+        .unwrap();
+
+        root
+    }
+
+    #[test]
+    fn creates_and_registers_a_day() {
+        let root = workspace("register");
+
+        let day = NewDay::parse("9").unwrap();
+        let created = scaffold(&root, &day).unwrap();
+
+        assert!(created.contains(&root.join("day_nine/benches/day_nine.rs")));
+        assert!(fs::read_to_string(root.join("day_nine/src/lib.rs"))
+            .unwrap()
+            .contains("impl Solution for DayNine {"));
+        assert!(fs::read_to_string(root.join("day_nine/src/main.rs"))
+            .unwrap()
+            .contains("const DAY: u8 = 9;"));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("    \"aoc\",\n    \"day_nine\",\n]"));
+        assert!(fs::read_to_string(root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .ends_with("day_nine = { path = \"../day_nine\" }\n"));
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("    (\"day_nine\", &day_nine::DayNine),\n];"));
+
+        assert_eq!(
+            scaffold(&root, &day),
+            Err(ScaffoldError::AlreadyExists(root.join("day_nine")))
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn leaves_the_workspace_alone_when_it_cant_register() {
+        let root = workspace("unregistered");
+
+        fs::write(root.join("aoc/src/days.rs"), "").unwrap();
+
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let day = NewDay::parse("9").unwrap();
+
+        assert_eq!(
+            scaffold(&root, &day),
+            Err(ScaffoldError::NoRegistry(root.join("aoc/src/days.rs")))
+        );
+        assert!(!root.join("day_nine").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            manifest
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    // Builds the scaffolded workspace from scratch, which takes a while, so
+    // it only runs when asked for with `cargo test -p aoc -- --ignored`
+    #[test]
+    #[ignore]
+    fn scaffolded_days_build() {
+        let root = workspace("build");
+        let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+        scaffold(&root, &NewDay::parse("9").unwrap()).unwrap();
+
+        // Resolved offline, so only against the dependencies already fetched
+        let status = Command::new(&cargo)
+            .args(["generate-lockfile", "--offline", "--quiet"])
+            .current_dir(&root)
+            .status()
+            .unwrap();
+
+        assert!(status.success());
+
+        let status = Command::new(&cargo)
+            .args(["check", "--offline", "--quiet"])
+            .args(["-p", "day_nine", "-p", "aoc"])
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", root.join("target"))
+            .status()
+            .unwrap();
+
+        assert!(status.success());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use std::{error::Error, fmt};
+
+use common::Solution;
+
+use crate::answers::Expected;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Ok,
+    Mismatch { expected: String, actual: String },
+    Failed(String),
+}
+
+// The result of checking one part of one input against its confirmed answer
+#[derive(Debug, PartialEq, Eq)]
+pub struct Check {
+    pub input: String,
+    pub part: &'static str,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Ok
+    }
+}
+
+// Lines of the expected answer prefixed with `-`, then those of the actual
+// answer prefixed with `+`
+pub fn diff(expected: &str, actual: &str) -> String {
+    expected
+        .lines()
+        .map(|line| format!("- {}", line))
+        .chain(actual.lines().map(|line| format!("+ {}", line)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.outcome {
+            Outcome::Ok => write!(f, "{} {}: ok", self.input, self.part),
+            Outcome::Mismatch { expected, actual } => write!(
+                f,
+                "{} {}: mismatch\n{}",
+                self.input,
+                self.part,
+                diff(expected, actual)
+            ),
+            Outcome::Failed(err) => write!(f, "{} {}: failed: {}", self.input, self.part, err),
+        }
+    }
+}
+
+fn check(
+    expected: &Expected,
+    part: &'static str,
+    answer: &Option<String>,
+    solve: impl FnOnce() -> Result<String, Box<dyn Error>>,
+) -> Option<Check> {
+    let answer = answer.as_ref()?;
+
+    let outcome = match solve() {
+        Ok(actual) if actual == *answer => Outcome::Ok,
+        Ok(actual) => Outcome::Mismatch {
+            expected: answer.to_owned(),
+            actual,
+        },
+        Err(err) => Outcome::Failed(err.to_string()),
+    };
+
+    Some(Check {
+        input: expected.input.to_owned(),
+        part,
+        outcome,
+    })
+}
+
+// Solves `input` and checks each part that has a confirmed answer
+pub fn verify(solution: &dyn Solution, expected: &Expected, input: &str) -> Vec<Check> {
+    [
+        check(expected, "part one", &expected.part_one, || {
+            solution.part_one(input)
+        }),
+        check(expected, "part two", &expected.part_two, || {
+            solution.part_two(input)
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+            Ok(input.len().to_string())
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, Box<dyn Error>> {
+            Err("unsolved".into())
+        }
+    }
+
+    #[test]
+    fn reports_mismatches_and_failures() {
+        let expected = Expected {
+            input: "input.txt".to_string(),
+            part_one: Some("4".to_string()),
+            part_two: Some("1".to_string()),
+        };
+
+        let checks = verify(&Lengths, &expected, "abc");
+
+        assert_eq!(
+            checks
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+            vec![
+                "input.txt part one: mismatch\n- 4\n+ 3",
+                "input.txt part two: failed: unsolved",
+            ]
+        );
+        assert!(checks.iter().all(|check| !check.passed()));
+    }
+
+    #[test]
+    fn skips_unconfirmed_parts() {
+        let expected = Expected {
+            input: "input.txt".to_string(),
+            part_one: Some("3".to_string()),
+            part_two: None,
+        };
+
+        let checks = verify(&Lengths, &expected, "abc");
+
+        assert_eq!(checks.len(), 1);
+        assert!(checks[0].passed());
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    env,
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+use crate::normalize::normalize;
+
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputError {
+    // Not cached, and there is no session token to fetch it with
+    MissingSession { year: u16, day: u8 },
+    Http { url: String, status: u16 },
+    Request { url: String, message: String },
+    Io { path: PathBuf, message: String },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::MissingSession { year, day } => write!(
+                f,
+                "no cached input for {} day {}, and AOC_SESSION isn't set to fetch it",
+                year, day
+            ),
+            InputError::Http { url, status } => write!(f, "{}: HTTP status {}", url, status),
+            InputError::Request { url, message } => write!(f, "{}: {}", url, message),
+            InputError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+fn io_error(path: &Path, err: std::io::Error) -> InputError {
+    InputError::Io {
+        path: path.to_owned(),
+        message: err.to_string(),
+    }
+}
+
+// Where puzzle inputs are cached, and where to fetch the ones that aren't
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inputs {
+    pub cache_dir: PathBuf,
+    pub endpoint: String,
+    pub session: Option<String>,
+}
+
+impl Inputs {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            session: None,
+        }
+    }
+
+    // `AOC_CACHE_DIR` (by default `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`),
+    // `AOC_ENDPOINT` and the `AOC_SESSION` cookie
+    pub fn from_env() -> Self {
+        let cache_dir = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("XDG_CACHE_HOME").map(|dir| PathBuf::from(dir).join("aoc")))
+            .or_else(|| env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".cache/aoc")))
+            .unwrap_or_else(|| PathBuf::from(".aoc-cache"));
+
+        Self {
+            cache_dir,
+            endpoint: env::var("AOC_ENDPOINT").unwrap_or_else(|_| DEFAULT_ENDPOINT.to_string()),
+            session: env::var("AOC_SESSION")
+                .ok()
+                .filter(|session| !session.is_empty()),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.endpoint.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+
+    // Where the input is cached, fetching and caching it first if it isn't
+    pub fn locate(&self, year: u16, day: u8) -> Result<PathBuf, InputError> {
+        let path = self.path(year, day);
+
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let input = self.fetch(year, day)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| io_error(dir, err))?;
+        }
+
+        // Written under another name first, so an interrupted write never
+        // leaves a truncated input in the cache
+        let partial = path.with_extension("part");
+
+        fs::write(&partial, &input).map_err(|err| io_error(&partial, err))?;
+        fs::rename(&partial, &path).map_err(|err| io_error(&path, err))?;
+
+        Ok(path)
+    }
+
+    // The cached input, or else the fetched one, which is cached for next time
+    pub fn resolve(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.locate(year, day)?;
+
+        fs::read_to_string(&path).map_err(|err| io_error(&path, err))
+    }
+
+    // The same input opened for reading, rather than read in full
+    pub fn open(&self, year: u16, day: u8) -> Result<BufReader<File>, InputError> {
+        let path = self.locate(year, day)?;
+
+        File::open(&path)
+            .map(BufReader::new)
+            .map_err(|err| io_error(&path, err))
+    }
+
+    fn fetch(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or(InputError::MissingSession { year, day })?;
+        let url = self.url(year, day);
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => InputError::Http {
+                    url: url.to_owned(),
+                    status,
+                },
+                err => InputError::Request {
+                    url: url.to_owned(),
+                    message: err.to_string(),
+                },
+            })?;
+
+        response.into_string().map_err(|err| InputError::Request {
+            url: url.to_owned(),
+            message: err.to_string(),
+        })
+    }
+}
+
+// The `input.txt` in the calling crate's own directory, wherever the binary is
+// run from
+#[macro_export]
+macro_rules! local_input {
+    () => {
+        ::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))
+    };
+}
+
+// `local`, the day's own input file, if there is one, as the days have always
+// read, or else the input located by the environment's `Inputs`
+pub fn input_path(local: &Path, year: u16, day: u8) -> Result<PathBuf, InputError> {
+    if local.exists() {
+        return Ok(local.to_owned());
+    }
+
+    Inputs::from_env().locate(year, day)
+}
+
+// The day's input, normalized
+pub fn read_input(local: &Path, year: u16, day: u8) -> Result<String, InputError> {
+    let path = input_path(local, year, day)?;
+    let input = fs::read_to_string(&path).map_err(|err| io_error(&path, err))?;
+
+    Ok(normalize(&input))
+}
+
+// The same input opened as it is, for streaming and for reporting offsets
+// into the file itself
+pub fn open_input(local: &Path, year: u16, day: u8) -> Result<BufReader<File>, InputError> {
+    let path = input_path(local, year, day)?;
+
+    File::open(&path)
+        .map(BufReader::new)
+        .map_err(|err| io_error(&path, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    // A cache directory of its own for each test, as they run in parallel
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn reads_cached_inputs_without_fetching() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", mockito::Matcher::Any).expect(0).create();
+
+        let dir = cache_dir("cached");
+        let inputs = Inputs {
+            endpoint: server.url(),
+            session: Some("secret".to_string()),
+            ..Inputs::new(&dir)
+        };
+
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        fs::write(
+            dir.join("2022/day06.txt"),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            inputs.resolve(2022, 6),
+            Ok("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".to_string())
+        );
+        mock.assert();
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn prefers_the_days_own_input() {
+        let dir = cache_dir("local");
+        let local = dir.join("input.txt");
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&local, "\u{feff}1000\r\n2000\r\n").unwrap();
+
+        assert_eq!(input_path(&local, 2022, 1), Ok(local.clone()));
+        assert_eq!(read_input(&local, 2022, 1), Ok("1000\n2000".to_string()));
+
+        let mut raw = vec![];
+
+        open_input(&local, 2022, 1)
+            .unwrap()
+            .read_to_end(&mut raw)
+            .unwrap();
+
+        assert_eq!(raw, "\u{feff}1000\r\n2000\r\n".as_bytes());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetches_missing_inputs_once_with_the_session() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2022/day/1/input")
+            .match_header("cookie", "session=secret")
+            .with_body("1000\n2000\n")
+            .expect(1)
+            .create();
+
+        let dir = cache_dir("fetched");
+        let inputs = Inputs {
+            endpoint: format!("{}/", server.url()),
+            session: Some("secret".to_string()),
+            ..Inputs::new(&dir)
+        };
+
+        assert_eq!(inputs.resolve(2022, 1), Ok("1000\n2000\n".to_string()));
+        assert_eq!(inputs.locate(2022, 1), Ok(dir.join("2022/day01.txt")));
+
+        let mut input = String::new();
+
+        inputs
+            .open(2022, 1)
+            .unwrap()
+            .read_to_string(&mut input)
+            .unwrap();
+
+        assert_eq!(input, "1000\n2000\n");
+        assert_eq!(
+            fs::read_to_string(dir.join("2022/day01.txt")).unwrap(),
+            "1000\n2000\n"
+        );
+        mock.assert();
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_failed_fetches_without_caching() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2022/day/26/input")
+            .with_status(404)
+            .create();
+
+        let dir = cache_dir("failed");
+        let inputs = Inputs {
+            endpoint: server.url(),
+            session: Some("secret".to_string()),
+            ..Inputs::new(&dir)
+        };
+
+        assert_eq!(
+            inputs.resolve(2022, 26),
+            Err(InputError::Http {
+                url: format!("{}/2022/day/26/input", server.url()),
+                status: 404
+            })
+        );
+        assert!(!inputs.path(2022, 26).exists());
+        mock.assert();
+    }
+
+    #[test]
+    fn needs_a_session_to_fetch() {
+        let inputs = Inputs::new(cache_dir("no-session"));
+
+        assert_eq!(
+            inputs.resolve(2022, 2),
+            Err(InputError::MissingSession { year: 2022, day: 2 })
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod inputs;
+pub mod normalize;
+
+use std::error::Error;
+
+// A day's puzzle, solved from the full text of an input file, which is
+// normalized first. Answers are strings so that days answering with letters
+// fit alongside the rest.
+pub trait Solution {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>>;
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>>;
+}
@@ -0,0 +1,75 @@
+const BOM: char = '\u{feff}';
+
+// Input as the days expect it whatever editor it went through: no byte order
+// mark, LF line endings, and no trailing whitespace or newlines at the end.
+// Leading whitespace is kept, as some drawings start with indented lines.
+pub fn normalize(input: &str) -> String {
+    normalize_line_endings(input).trim_end().to_string()
+}
+
+// Only the byte order mark and line endings, for days where a blank line at
+// the end is an error worth reporting rather than an editor's leftover
+pub fn normalize_line_endings(input: &str) -> String {
+    input
+        .strip_prefix(BOM)
+        .unwrap_or(input)
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+}
+
+// The sections of the input separated by blank lines. Runs of blank lines,
+// and lines of nothing but whitespace, count as a single separator.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut section: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some((start, end)) = section.take() {
+                sections.push(&input[start..end]);
+            }
+        } else {
+            let start = section.map_or(offset, |(start, _)| start);
+
+            section = Some((start, offset + content.len()));
+        }
+
+        offset += line.len();
+    }
+
+    if let Some((start, end)) = section {
+        sections.push(&input[start..end]);
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_windows_edited_input() {
+        assert_eq!(
+            normalize("\u{feff}1000\r\n2000\r\n\r\n3000\r\n"),
+            "1000\n2000\n\n3000"
+        );
+        assert_eq!(normalize("a\rb\n\n\n"), "a\nb");
+        assert_eq!(normalize("    [D]\n[N] [C]\n"), "    [D]\n[N] [C]");
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize_line_endings("\u{feff}a\r\n\r\n"), "a\n\n");
+    }
+
+    #[test]
+    fn splits_on_runs_of_blank_lines() {
+        assert_eq!(
+            sections("\n    [D]\n[N] [C]\n\n \t\n\nmove 1 from 2 to 1\n"),
+            vec!["    [D]\n[N] [C]", "move 1 from 2 to 1"]
+        );
+        assert_eq!(sections("1\r\n2\r\n\r\n3"), vec!["1\r\n2", "3"]);
+        assert!(sections("\n\n").is_empty());
+    }
+}
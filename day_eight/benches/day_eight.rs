@@ -0,0 +1,37 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_eight::{count_visible_trees, find_best_scenic_score, parse_forest};
+use generator::generate;
+
+const SAMPLE: &str = "30373
+25512
+65332
+33549
+35390";
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let (forest, width, height) = parse_forest(input).unwrap();
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| b.iter(|| parse_forest(black_box(input))));
+    group.bench_function("part one", |b| {
+        b.iter(|| count_visible_trees(black_box(&forest), width, height))
+    });
+    group.bench_function("part two", |b| {
+        b.iter(|| find_best_scenic_score(black_box(&forest), width, height))
+    });
+
+    group.finish();
+}
+
+fn day_eight(c: &mut Criterion) {
+    bench(c, "day_eight/sample", SAMPLE);
+    bench(c, "day_eight/scaled", &generate(316, 2022));
+}
+
+criterion_group!(benches, day_eight);
+criterion_main!(benches);
@@ -0,0 +1,16 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// A `size` by `size` forest of tree heights 0 to 9
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.gen_range(0..=9)))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
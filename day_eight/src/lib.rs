@@ -0,0 +1,389 @@
+#[cfg(test)]
+mod generator;
+
+use std::{error::Error, fmt};
+
+use common::normalize::normalize;
+use common::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ForestError {
+    Empty,
+    InvalidTree {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    // Every row must be as wide as the first
+    Ragged {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+}
+
+impl fmt::Display for ForestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForestError::Empty => write!(f, "the forest has no trees"),
+            ForestError::InvalidTree {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {}, column {}: {:?} is not a tree height",
+                line, column, found
+            ),
+            ForestError::Ragged {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "line {} has {} trees but the first line has {}",
+                line, width, expected
+            ),
+        }
+    }
+}
+
+impl Error for ForestError {}
+
+// Tree heights row by row, along with the width and height of the forest
+pub fn parse_forest(input: &str) -> Result<(Vec<usize>, usize, usize), ForestError> {
+    let rows = input.lines().map(|l| l.trim()).collect::<Vec<&str>>();
+
+    let width = rows.first().map_or(0, |row| row.chars().count());
+    let height = rows.len();
+
+    if width == 0 {
+        return Err(ForestError::Empty);
+    }
+
+    let mut forest = Vec::with_capacity(width * height);
+
+    for (y, row) in rows.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            let tree = c.to_digit(10).ok_or(ForestError::InvalidTree {
+                line: y + 1,
+                column: x + 1,
+                found: c,
+            })?;
+
+            forest.push(tree as usize);
+        }
+
+        if forest.len() != width * (y + 1) {
+            return Err(ForestError::Ragged {
+                line: y + 1,
+                width: row.chars().count(),
+                expected: width,
+            });
+        }
+    }
+
+    Ok((forest, width, height))
+}
+
+pub fn index_to_coords(i: usize, width: usize) -> (usize, usize) {
+    let x = i % (width);
+    let y = i / (width);
+
+    (x, y)
+}
+
+pub fn coords_to_index(coords: (usize, usize), width: usize) -> usize {
+    let (x, y) = coords;
+
+    x + ((width) * y)
+}
+
+pub fn find_neighbors(coords: (usize, usize), width: usize, height: usize) -> [Vec<usize>; 4] {
+    let (x, y) = coords;
+
+    let top: Vec<usize> = (0..y).map(|n| coords_to_index((x, n), width)).collect();
+    let right: Vec<usize> = (x + 1..width)
+        .map(|n| coords_to_index((n, y), width))
+        .collect();
+    let bottom: Vec<usize> = (y + 1..height)
+        .map(|n| coords_to_index((x, n), width))
+        .collect();
+    let left: Vec<usize> = (0..x).map(|n| coords_to_index((n, y), width)).collect();
+
+    [top, right, bottom, left]
+}
+
+pub fn count_visible_trees(forest: &[usize], width: usize, height: usize) -> Vec<usize> {
+    forest
+        .iter()
+        .enumerate()
+        .filter_map(|(i, tree)| {
+            let (x, y) = index_to_coords(i, width);
+
+            // Edges are always eligible
+            // top | left | bottom | right
+            if x == 0 || y == 0 || y == (height - 1) || x == (width - 1) {
+                return Some(*tree);
+            }
+
+            let neighbors = find_neighbors((x, y), width, height);
+
+            let is_visible = neighbors
+                .iter()
+                .any(|line| line.iter().all(|j| forest[*j] < *tree));
+
+            if is_visible {
+                return Some(*tree);
+            }
+
+            None
+        })
+        .collect::<Vec<usize>>()
+}
+
+pub enum Direction {
+    Pos,
+    Neg,
+}
+
+pub fn get_view(tree: usize, line: Vec<usize>, direction: Direction) -> usize {
+    let line: Vec<&usize> = match direction {
+        Direction::Pos => line.iter().collect(),
+        Direction::Neg => line.iter().rev().collect(),
+    };
+
+    let mut count: usize = 0;
+
+    for neighbor in line.iter() {
+        count += 1;
+        if *neighbor >= &tree {
+            break;
+        }
+    }
+
+    count
+}
+
+pub fn get_scenic_score(tree: usize, neighbors: [Vec<usize>; 4]) -> usize {
+    let [top, right, bottom, left] = neighbors;
+
+    let top_view = get_view(tree, top, Direction::Neg);
+    let right_view = get_view(tree, right, Direction::Pos);
+    let bottom_view = get_view(tree, bottom, Direction::Pos);
+    let left_view = get_view(tree, left, Direction::Neg);
+
+    top_view * right_view * bottom_view * left_view
+}
+
+pub fn find_best_scenic_score(forest: &[usize], width: usize, height: usize) -> usize {
+    let scenic_scores = forest.iter().enumerate().map(|(i, tree)| {
+        let (x, y) = index_to_coords(i, width);
+
+        // Edges are always 0
+        // top | left | bottom | right
+        if x == 0 || y == 0 || y == (height - 1) || x == (width - 1) {
+            return 0;
+        }
+
+        let [top, right, bottom, left] = find_neighbors((x, y), width, height);
+
+        let neighbors: [Vec<usize>; 4] = [
+            top.iter().map(|j| forest[*j]).collect::<Vec<usize>>(),
+            right.iter().map(|j| forest[*j]).collect::<Vec<usize>>(),
+            bottom.iter().map(|j| forest[*j]).collect::<Vec<usize>>(),
+            left.iter().map(|j| forest[*j]).collect::<Vec<usize>>(),
+        ];
+
+        get_scenic_score(*tree, neighbors)
+    });
+
+    scenic_scores.max().unwrap()
+}
+
+pub struct DayEight;
+
+impl Solution for DayEight {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let (forest, width, height) = parse_forest(&normalize(input))?;
+
+        Ok(count_visible_trees(&forest, width, height)
+            .len()
+            .to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let (forest, width, height) = parse_forest(&normalize(input))?;
+
+        Ok(find_best_scenic_score(&forest, width, height).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_coords_to_index() {
+        let width = 5;
+        // top left
+        assert_eq!(coords_to_index((0, 0), width), 0);
+        // top right
+        assert_eq!(coords_to_index((4, 0), width), 4);
+        // botom left
+        assert_eq!(coords_to_index((0, 4), width), 20);
+        // bottom right
+        assert_eq!(coords_to_index((4, 4), width), 24);
+        // somewhere in the middle
+        assert_eq!(coords_to_index((1, 1), width), 6)
+    }
+
+    #[test]
+    fn test_index_to_coords() {
+        let width = 5;
+        // top left
+        assert_eq!(index_to_coords(0, width), (0, 0));
+        // top right
+        assert_eq!(index_to_coords(4, width), (4, 0));
+        // botom left
+        assert_eq!(index_to_coords(20, width), (0, 4));
+        // bottom right
+        assert_eq!(index_to_coords(24, width), (4, 4));
+        // somewhere in the middle
+        assert_eq!(index_to_coords(6, width), (1, 1))
+    }
+
+    #[test]
+    fn neighbors_from_coords() {
+        let coords: (usize, usize) = (1, 1);
+        let width = 5;
+        let height = 5;
+
+        let output = find_neighbors(coords, width, height);
+
+        assert_eq!(output, [vec![1], vec![7, 8, 9], vec![11, 16, 21], vec![5]])
+    }
+
+    const FOREST: [usize; 25] = [
+        3, 0, 3, 7, 3, 2, 5, 5, 1, 2, 6, 5, 3, 3, 2, 3, 3, 5, 4, 9, 3, 5, 3, 9, 0,
+    ];
+
+    #[test]
+    fn small_forest() {
+        let forest: Vec<usize> = FOREST.to_vec();
+        let visible_trees = count_visible_trees(&forest, 5, 5);
+
+        assert_eq!(visible_trees.len(), 21)
+    }
+
+    #[test]
+    fn small_forest_best_scenic_score() {
+        let forest: Vec<usize> = FOREST.to_vec();
+
+        assert_eq!(find_best_scenic_score(&forest, 5, 5), 8);
+    }
+
+    #[test]
+    fn find_scenic_score() {
+        let tree = 5;
+        let neighbors = [vec![3], vec![1, 2], vec![3, 5, 3], vec![2, 5]];
+
+        assert_eq!(get_scenic_score(tree, neighbors), 4);
+
+        let tree = 5;
+        let neighbors = [vec![3, 5, 3], vec![4, 9], vec![3], vec![3, 3]];
+        assert_eq!(get_scenic_score(tree, neighbors), 8);
+    }
+
+    #[test]
+    fn get_view_count() {
+        let tree = 5;
+
+        let neighbors = vec![3, 5, 3];
+
+        assert_eq!(get_view(tree, neighbors, Direction::Neg), 2);
+
+        let neighbors = vec![4, 9];
+
+        assert_eq!(get_view(tree, neighbors, Direction::Pos), 2);
+        let neighbors = vec![3];
+
+        assert_eq!(get_view(tree, neighbors, Direction::Pos), 1);
+        let neighbors = vec![3, 5, 3];
+
+        assert_eq!(get_view(tree, neighbors, Direction::Neg), 2);
+    }
+
+    #[test]
+    fn rejects_malformed_forests() {
+        assert_eq!(parse_forest(""), Err(ForestError::Empty));
+        assert_eq!(
+            parse_forest("303\n2a5"),
+            Err(ForestError::InvalidTree {
+                line: 2,
+                column: 2,
+                found: 'a'
+            })
+        );
+        assert_eq!(
+            parse_forest("303\n25\n653"),
+            Err(ForestError::Ragged {
+                line: 2,
+                width: 2,
+                expected: 3
+            })
+        );
+        assert_eq!(parse_forest("30\r\n25\r\n"), Ok((vec![3, 0, 2, 5], 2, 2)));
+    }
+
+    #[test]
+    fn windows_edited_input() {
+        let input = "\u{feff}30373\r\n25512\r\n65332\r\n33549\r\n35390\r\n\r\n";
+
+        assert_eq!(DayEight.part_one(input).unwrap(), "21");
+        assert_eq!(DayEight.part_two(input).unwrap(), "8");
+    }
+
+    proptest! {
+        #[test]
+        fn coords_and_index_round_trip(width in 1usize..1000, height in 1usize..1000, seed: usize) {
+            let i = seed % (width * height);
+            let (x, y) = index_to_coords(i, width);
+
+            prop_assert!(x < width && y < height);
+            prop_assert_eq!(coords_to_index((x, y), width), i);
+        }
+
+        #[test]
+        fn neighbors_lie_on_the_same_row_and_column(
+            width in 1usize..50,
+            height in 1usize..50,
+            seed: usize,
+        ) {
+            let coords = index_to_coords(seed % (width * height), width);
+            let [top, right, bottom, left] = find_neighbors(coords, width, height);
+
+            prop_assert_eq!(top.len() + bottom.len(), height - 1);
+            prop_assert_eq!(left.len() + right.len(), width - 1);
+
+            for j in top.iter().chain(&bottom) {
+                prop_assert_eq!(index_to_coords(*j, width).0, coords.0);
+            }
+
+            for j in left.iter().chain(&right) {
+                prop_assert_eq!(index_to_coords(*j, width).1, coords.1);
+            }
+        }
+    }
+
+    #[test]
+    fn generates_square_forests() {
+        let input = generate(50, 8);
+
+        let (forest, width, height) = parse_forest(&input).unwrap();
+
+        assert_eq!((forest.len(), width, height), (2500, 50, 50));
+    }
+}
@@ -0,0 +1,55 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_five::crane::Crane;
+use day_five::stack::Stack;
+use day_five::{parse_raw_stacks, Instruction};
+use generator::generate;
+
+const SAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+fn parse(input: &str) -> (Vec<Stack>, Vec<Instruction>) {
+    let (drawing, instructions) = input.split_once("\n\n").unwrap();
+
+    (
+        parse_raw_stacks(drawing).unwrap(),
+        instructions
+            .lines()
+            .map(|line| line.parse::<Instruction>().unwrap())
+            .collect(),
+    )
+}
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let (stacks, instructions) = parse(input);
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+    group.bench_function("part one", |b| {
+        b.iter(|| Crane::CrateMover9000.run(black_box(&stacks), &instructions))
+    });
+    group.bench_function("part two", |b| {
+        b.iter(|| Crane::CrateMover9001.run(black_box(&stacks), &instructions))
+    });
+
+    group.finish();
+}
+
+fn day_five(c: &mut Criterion) {
+    bench(c, "day_five/sample", SAMPLE);
+    bench(c, "day_five/scaled", &generate(5000, 2022));
+}
+
+criterion_group!(benches, day_five);
+criterion_main!(benches);
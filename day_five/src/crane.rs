@@ -0,0 +1,293 @@
+use std::{error::Error, fmt};
+
+use crate::stack::Stack;
+use crate::Instruction;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CraneError {
+    // The instruction names a stack that isn't in the drawing
+    NoSuchStack {
+        instruction: Instruction,
+        stack: usize,
+    },
+    // The source stack holds fewer crates than the instruction moves
+    NotEnoughCrates {
+        instruction: Instruction,
+        found: usize,
+    },
+}
+
+impl fmt::Display for CraneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CraneError::NoSuchStack { instruction, stack } => {
+                write!(f, "{}: there is no stack {}", instruction, stack + 1)
+            }
+            CraneError::NotEnoughCrates { instruction, found } => write!(
+                f,
+                "{}: stack {} only holds {} crates",
+                instruction,
+                instruction.src + 1,
+                found
+            ),
+        }
+    }
+}
+
+impl Error for CraneError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Crane {
+    // Moves crates one at a time, so a moved group lands reversed
+    CrateMover9000,
+    // Moves a group of crates at once, keeping their order
+    CrateMover9001,
+}
+
+impl Crane {
+    // Checked before moving anything, so a rejected instruction leaves the
+    // stacks as they were
+    fn check(stacks: &[Stack], instruction: &Instruction) -> Result<(), CraneError> {
+        for stack in [instruction.src, instruction.dest] {
+            if stack >= stacks.len() {
+                return Err(CraneError::NoSuchStack {
+                    instruction: instruction.clone(),
+                    stack,
+                });
+            }
+        }
+
+        let found = stacks[instruction.src].as_slice().len();
+
+        if found < instruction.m {
+            return Err(CraneError::NotEnoughCrates {
+                instruction: instruction.clone(),
+                found,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(&self, stacks: &mut [Stack], instruction: &Instruction) -> Result<(), CraneError> {
+        Self::check(stacks, instruction)?;
+
+        let Instruction { m, src, dest } = *instruction;
+
+        match self {
+            Crane::CrateMover9000 => {
+                for _ in 0..m {
+                    if let Some(value) = stacks[src].pop() {
+                        stacks[dest].push(value)
+                    }
+                }
+            }
+            Crane::CrateMover9001 => {
+                let mut temp = Stack::new();
+
+                for _ in 0..m {
+                    if let Some(value) = stacks[src].pop() {
+                        temp.push(value);
+                    }
+                }
+
+                while let Some(c) = temp.pop() {
+                    stacks[dest].push(c);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn run(
+        &self,
+        stacks: &[Stack],
+        instructions: &[Instruction],
+    ) -> Result<Vec<Stack>, CraneError> {
+        let mut state = stacks.to_vec();
+
+        for instruction in instructions {
+            self.apply(&mut state, instruction)?;
+        }
+
+        Ok(state)
+    }
+
+    // The order, bottom to top, in which `block` ends up on the destination stack
+    pub fn landed_order(&self, block: &[char]) -> Vec<char> {
+        match self {
+            Crane::CrateMover9000 => block.iter().rev().copied().collect(),
+            Crane::CrateMover9001 => block.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn stacks() -> impl Strategy<Value = Vec<Stack>> {
+        prop::collection::vec(prop::collection::vec(b'A'..=b'Z', 0..10), 3..9).prop_map(|stacks| {
+            stacks
+                .into_iter()
+                .map(|crates| {
+                    let mut stack = Stack::new();
+
+                    for c in crates {
+                        stack.push(c as char);
+                    }
+
+                    stack
+                })
+                .collect()
+        })
+    }
+
+    // Turns arbitrary numbers into moves that only take crates which are
+    // there, by playing them out as they are chosen
+    fn valid_moves(stacks: &[Stack], picks: &[(usize, usize, usize)]) -> Vec<Instruction> {
+        let mut heights = stacks
+            .iter()
+            .map(|stack| stack.as_slice().len())
+            .collect::<Vec<usize>>();
+        let mut instructions = vec![];
+
+        for (src, dest, m) in picks {
+            let full = (0..heights.len())
+                .filter(|i| heights[*i] > 0)
+                .collect::<Vec<usize>>();
+
+            if full.is_empty() {
+                break;
+            }
+
+            let src = full[src % full.len()];
+            let dest = (src + 1 + dest % (heights.len() - 1)) % heights.len();
+            let m = 1 + m % heights[src];
+
+            heights[src] -= m;
+            heights[dest] += m;
+
+            instructions.push(Instruction { m, src, dest });
+        }
+
+        instructions
+    }
+
+    fn sorted_crates(stacks: &[Stack]) -> Vec<char> {
+        let mut crates = stacks
+            .iter()
+            .flat_map(|stack| stack.as_slice().to_vec())
+            .collect::<Vec<char>>();
+
+        crates.sort_unstable();
+
+        crates
+    }
+
+    #[test]
+    fn rejects_moves_the_stacks_cant_make() {
+        let stacks = vec![Stack::from_str("A"), Stack::from_str("")];
+
+        for crane in [Crane::CrateMover9000, Crane::CrateMover9001] {
+            let mut state = stacks.clone();
+
+            assert_eq!(
+                crane.apply(
+                    &mut state,
+                    &Instruction {
+                        m: 1,
+                        src: 8,
+                        dest: 0
+                    }
+                ),
+                Err(CraneError::NoSuchStack {
+                    instruction: Instruction {
+                        m: 1,
+                        src: 8,
+                        dest: 0
+                    },
+                    stack: 8
+                })
+            );
+            assert_eq!(
+                crane.apply(
+                    &mut state,
+                    &Instruction {
+                        m: 1,
+                        src: 0,
+                        dest: 2
+                    }
+                ),
+                Err(CraneError::NoSuchStack {
+                    instruction: Instruction {
+                        m: 1,
+                        src: 0,
+                        dest: 2
+                    },
+                    stack: 2
+                })
+            );
+            assert_eq!(
+                crane.apply(
+                    &mut state,
+                    &Instruction {
+                        m: 2,
+                        src: 0,
+                        dest: 1
+                    }
+                ),
+                Err(CraneError::NotEnoughCrates {
+                    instruction: Instruction {
+                        m: 2,
+                        src: 0,
+                        dest: 1
+                    },
+                    found: 1
+                })
+            );
+            assert_eq!(
+                crane.apply(
+                    &mut state,
+                    &Instruction {
+                        m: 1,
+                        src: 1,
+                        dest: 0
+                    }
+                ),
+                Err(CraneError::NotEnoughCrates {
+                    instruction: Instruction {
+                        m: 1,
+                        src: 1,
+                        dest: 0
+                    },
+                    found: 0
+                })
+            );
+            assert_eq!(state, stacks);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn moves_conserve_crates(
+            stacks in stacks(),
+            picks in prop::collection::vec((any::<usize>(), any::<usize>(), any::<usize>()), 0..50),
+        ) {
+            let instructions = valid_moves(&stacks, &picks);
+
+            let one_at_a_time = Crane::CrateMover9000.run(&stacks, &instructions).unwrap();
+            let all_at_once = Crane::CrateMover9001.run(&stacks, &instructions).unwrap();
+
+            prop_assert_eq!(sorted_crates(&one_at_a_time), sorted_crates(&stacks));
+            prop_assert_eq!(sorted_crates(&all_at_once), sorted_crates(&stacks));
+
+            // Both cranes move the same number of crates between the same stacks
+            for (a, b) in one_at_a_time.iter().zip(&all_at_once) {
+                prop_assert_eq!(a.as_slice().len(), b.as_slice().len());
+            }
+        }
+    }
+}
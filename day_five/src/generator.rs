@@ -0,0 +1,70 @@
+use rand::{seq::IteratorRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+pub(crate) const STACKS: usize = 9;
+
+// The drawing of `stacks`, top row first, followed by the stack numbers
+fn draw(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut lines = (0..height)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>();
+
+    lines.push(
+        (1..=stacks.len())
+            .map(|i| format!(" {} ", i))
+            .collect::<Vec<String>>()
+            .join(" "),
+    );
+
+    lines.join("\n")
+}
+
+// A drawing of nine stacks followed by `moves` moves. Every move takes crates
+// off a stack that has enough of them, whichever crane carries it out.
+pub fn generate(moves: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let mut stacks = (0..STACKS)
+        .map(|_| {
+            let height = rng.gen_range(1..=12);
+
+            (0..height)
+                .map(|_| rng.gen_range(b'A'..=b'Z') as char)
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    let drawing = draw(&stacks);
+
+    let instructions = (0..moves)
+        .map(|_| {
+            let src = (0..STACKS)
+                .filter(|i| !stacks[*i].is_empty())
+                .choose(&mut rng)
+                .unwrap();
+            let dest = (0..STACKS).filter(|i| *i != src).choose(&mut rng).unwrap();
+            let m = rng.gen_range(1..=stacks[src].len().min(10));
+
+            let at = stacks[src].len() - m;
+            let moved = stacks[src].split_off(at);
+
+            stacks[dest].extend(moved);
+
+            format!("move {} from {} to {}", m, src + 1, dest + 1)
+        })
+        .collect::<Vec<String>>();
+
+    format!("{}\n\n{}", drawing, instructions.join("\n"))
+}
@@ -0,0 +1,272 @@
+pub mod crane;
+#[cfg(test)]
+mod generator;
+pub mod planner;
+pub mod stack;
+
+use std::{error::Error, fmt, str::FromStr};
+
+use common::normalize::{normalize, sections};
+use common::Solution;
+
+use crane::Crane;
+use stack::Stack;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub m: usize,
+    pub src: usize,
+    pub dest: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    // Expected `move <m> from <src> to <dest>`, with stacks numbered from 1
+    InvalidInstruction(String),
+    // The last line of a drawing numbers the stacks 1, 2, 3, ...
+    InvalidStackNumbers(String),
+    // A crate sits to the right of the last numbered stack
+    CrateOutsideStacks { line: usize, stack: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidInstruction(line) => write!(f, "invalid instruction {:?}", line),
+            ParseError::InvalidStackNumbers(line) => {
+                write!(f, "invalid stack numbers {:?}", line)
+            }
+            ParseError::CrateOutsideStacks { line, stack } => write!(
+                f,
+                "line {} of the drawing has a crate in stack {}, which isn't numbered",
+                line, stack
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut inst = input
+            .split_whitespace()
+            .filter_map(|inst| inst.parse::<usize>().ok());
+
+        let invalid = || ParseError::InvalidInstruction(input.to_string());
+
+        let m = inst.next().ok_or_else(invalid)?;
+        let src = inst
+            .next()
+            .and_then(|inst| inst.checked_sub(1))
+            .ok_or_else(invalid)?;
+        let dest = inst
+            .next()
+            .and_then(|inst| inst.checked_sub(1))
+            .ok_or_else(invalid)?;
+
+        Ok(Self { m, src, dest })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.m,
+            self.src + 1,
+            self.dest + 1
+        )
+    }
+}
+
+fn filter_alphabetics(input: &[char]) -> Vec<&char> {
+    input.iter().filter(|c| c.is_alphabetic()).collect()
+}
+
+pub fn parse_raw_stacks(raw_stacks: &str) -> Result<Vec<Stack>, ParseError> {
+    let mut lines = raw_stacks.lines().rev();
+
+    let numbers = lines.next().unwrap_or_default();
+
+    // Checking every number, rather than trusting the last one, also keeps a
+    // huge number from allocating that many stacks
+    let numbered = numbers
+        .split_whitespace()
+        .enumerate()
+        .all(|(i, n)| n.parse::<usize>() == Ok(i + 1));
+
+    let num_stacks = numbers.split_whitespace().count();
+
+    if !numbered || num_stacks == 0 {
+        return Err(ParseError::InvalidStackNumbers(numbers.to_string()));
+    }
+
+    let mut stacks = vec![Stack::new(); num_stacks];
+
+    let height = raw_stacks.lines().count() - 1;
+
+    for (i, line) in lines.enumerate() {
+        let chars = line.chars().collect::<Vec<char>>();
+        let chunks = chars
+            .chunks(4)
+            .map(filter_alphabetics)
+            .collect::<Vec<Vec<&char>>>();
+
+        for (stack, chunk) in chunks.iter().enumerate() {
+            if let Some(c) = chunk.first() {
+                stacks
+                    .get_mut(stack)
+                    .ok_or(ParseError::CrateOutsideStacks {
+                        line: height - i,
+                        stack: stack + 1,
+                    })?
+                    .push(**c)
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+// Runs the input's instructions with `crane` and reads off the top crates
+fn top_crates(crane: Crane, input: &str) -> Result<String, Box<dyn Error>> {
+    let input = normalize(input);
+
+    let [drawing, instructions] = sections(&input)[..] else {
+        return Err("expected a drawing and instructions".into());
+    };
+
+    let stacks = parse_raw_stacks(drawing)?;
+    let instructions = instructions
+        .lines()
+        .map(str::parse::<Instruction>)
+        .collect::<Result<Vec<Instruction>, _>>()?;
+
+    Ok(crane
+        .run(&stacks, &instructions)?
+        .iter()
+        .filter_map(Stack::peek)
+        .collect())
+}
+
+pub struct DayFive;
+
+impl Solution for DayFive {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        top_crates(Crane::CrateMover9000, input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        top_crates(Crane::CrateMover9001, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::generator::{generate, STACKS};
+
+    const SAMPLE_INPUT: &str = "[D]        
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3";
+
+    #[test]
+    fn windows_edited_input() {
+        let input = "\u{feff}    [D]    \r\n[N] [C]    \r\n[Z] [M] [P]\r\n 1   2   3 \r\n\r\n\
+                     move 1 from 2 to 1\r\nmove 3 from 1 to 3\r\n\
+                     move 2 from 2 to 1\r\nmove 1 from 1 to 2\r\n";
+
+        assert_eq!(DayFive.part_one(input).unwrap(), "CMZ");
+        assert_eq!(DayFive.part_two(input).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn rejects_moves_outside_the_drawing() {
+        assert_eq!(
+            DayFive
+                .part_one("[A]\n 1 \n\nmove 1 from 9 to 1\n")
+                .unwrap_err()
+                .to_string(),
+            "move 1 from 9 to 1: there is no stack 9"
+        );
+        assert!(DayFive
+            .part_two("[A]\n 1 \n\nmove 2 from 1 to 1\n")
+            .is_err());
+    }
+
+    #[test]
+    fn do_a_thing() {
+        let out = parse_raw_stacks(SAMPLE_INPUT).unwrap();
+
+        let expected = vec![
+            Stack::from_str("ZND"),
+            Stack::from_str("MC"),
+            Stack::from_str("P"),
+        ];
+
+        assert_eq!(out, expected);
+
+        let first = out.first().unwrap();
+
+        assert_eq!(first.peek(), Some(&'D'));
+    }
+
+    #[test]
+    fn rejects_malformed_drawings_and_instructions() {
+        assert_eq!(
+            parse_raw_stacks("[A]\n 1   3"),
+            Err(ParseError::InvalidStackNumbers(" 1   3".to_string()))
+        );
+        assert_eq!(
+            parse_raw_stacks(""),
+            Err(ParseError::InvalidStackNumbers("".to_string()))
+        );
+        assert_eq!(
+            parse_raw_stacks("[A] [B]\n 1"),
+            Err(ParseError::CrateOutsideStacks { line: 1, stack: 2 })
+        );
+        assert_eq!(
+            "move 1 from 0 to 2".parse::<Instruction>(),
+            Err(ParseError::InvalidInstruction(
+                "move 1 from 0 to 2".to_string()
+            ))
+        );
+        assert!("move 1 from 2".parse::<Instruction>().is_err());
+        assert_eq!(
+            "move 3 from 1 to 2".parse::<Instruction>(),
+            Ok(Instruction {
+                m: 3,
+                src: 0,
+                dest: 1
+            })
+        );
+    }
+
+    #[test]
+    fn moves_only_take_crates_that_are_there() {
+        let input = generate(1000, 9);
+
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+
+        let stacks = parse_raw_stacks(drawing).unwrap();
+        let moves = moves
+            .lines()
+            .map(str::parse::<Instruction>)
+            .collect::<Result<Vec<Instruction>, _>>()
+            .unwrap();
+
+        assert_eq!(stacks.len(), STACKS);
+        assert_eq!(moves.len(), 1000);
+
+        // Every move only takes crates that are there
+        for crane in [Crane::CrateMover9000, Crane::CrateMover9001] {
+            assert!(crane.run(&stacks, &moves).is_ok());
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::crane::Crane;
+use crate::stack::Stack;
+use crate::Instruction;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PlanError {
+    StackCountMismatch { start: usize, target: usize },
+    CrateMismatch,
+    // Two stacks only ever shuffle crates back and forth, so most layouts are unreachable
+    TooFewStacks,
+}
+
+// Length of the bottom part of `current` that already matches `target`.
+// Crates in that part are never moved again.
+fn settled(current: &[char], target: &[char]) -> usize {
+    current
+        .iter()
+        .zip(target)
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+struct Layout<'a> {
+    current: Vec<&'a [char]>,
+    target: Vec<&'a [char]>,
+    settled: Vec<usize>,
+}
+
+impl<'a> Layout<'a> {
+    fn new(current: &'a [Stack], target: &'a [Stack]) -> Self {
+        let current = current
+            .iter()
+            .map(Stack::as_slice)
+            .collect::<Vec<&[char]>>();
+        let target = target.iter().map(Stack::as_slice).collect::<Vec<&[char]>>();
+        let settled = current
+            .iter()
+            .zip(&target)
+            .map(|(c, t)| settled(c, t))
+            .collect();
+
+        Self {
+            current,
+            target,
+            settled,
+        }
+    }
+
+    fn unsettled(&self, i: usize) -> usize {
+        self.current[i].len() - self.settled[i]
+    }
+
+    // Nothing unsettled on top, but still missing crates
+    fn is_open(&self, i: usize) -> bool {
+        self.unsettled(i) == 0 && self.settled[i] < self.target[i].len()
+    }
+
+    fn open_stacks(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.current.len()).filter(|i| self.is_open(*i))
+    }
+
+    // Somewhere to drop crates taken off another stack, preferring stacks that
+    // are already cluttered over ones that are waiting for a crate
+    fn spare_stack(&self, exclude: &[usize]) -> usize {
+        (0..self.current.len())
+            .filter(|k| !exclude.contains(k))
+            .min_by_key(|k| (self.is_open(*k), self.target[*k].len() - self.settled[*k]))
+            .unwrap()
+    }
+
+    // Move crates straight from the top of one stack onto the open stack that
+    // needs them next, taking as many at once as the crane allows
+    fn direct_move(&self, crane: Crane) -> Option<Instruction> {
+        let mut best: Option<Instruction> = None;
+
+        for dest in self.open_stacks() {
+            let needed = &self.target[dest][self.settled[dest]..];
+
+            for src in (0..self.current.len()).filter(|j| *j != dest) {
+                let len = self.current[src].len();
+                let max = self.unsettled(src).min(needed.len());
+
+                let m = (1..=max)
+                    .rev()
+                    .find(|m| crane.landed_order(&self.current[src][len - m..]) == needed[..*m]);
+
+                if let Some(m) = m {
+                    if best.as_ref().is_none_or(|b| m > b.m) {
+                        best = Some(Instruction { m, src, dest });
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    // Clear the crates sitting on top of the closest crate an open stack needs
+    fn dig_move(&self) -> Option<Instruction> {
+        let mut best: Option<(usize, usize, usize)> = None;
+
+        for dest in self.open_stacks() {
+            let needed = self.target[dest][self.settled[dest]];
+
+            for src in (0..self.current.len()).filter(|j| *j != dest) {
+                let stack = self.current[src];
+
+                let depth = stack[self.settled[src]..]
+                    .iter()
+                    .rev()
+                    .position(|c| *c == needed);
+
+                if let Some(depth) = depth {
+                    if best.is_none_or(|(d, _, _)| depth < d) {
+                        best = Some((depth, src, dest));
+                    }
+                }
+            }
+        }
+
+        best.map(|(m, src, dest)| Instruction {
+            m,
+            src,
+            dest: self.spare_stack(&[src, dest]),
+        })
+    }
+
+    // With no open stack, empty the unsettled crates off the stack with the
+    // fewest of them so that it opens up
+    fn clear_move(&self) -> Instruction {
+        let src = (0..self.current.len())
+            .filter(|i| self.settled[*i] < self.target[*i].len())
+            .min_by_key(|i| self.unsettled(*i))
+            .unwrap();
+
+        Instruction {
+            m: self.unsettled(src),
+            src,
+            dest: self.spare_stack(&[src]),
+        }
+    }
+}
+
+fn sorted_crates(stacks: &[Stack]) -> Vec<char> {
+    let mut crates = stacks
+        .iter()
+        .flat_map(|stack| stack.as_slice().iter().copied())
+        .collect::<Vec<char>>();
+
+    crates.sort_unstable();
+
+    crates
+}
+
+// Works out a sequence of instructions which, when carried out by `crane`,
+// rearranges `start` into `target`. Every instruction either settles crates
+// into their final place or uncovers one that is needed next, so the plan is
+// short but not necessarily the shortest possible.
+pub fn plan(
+    start: &[Stack],
+    target: &[Stack],
+    crane: Crane,
+) -> Result<Vec<Instruction>, PlanError> {
+    if start.len() != target.len() {
+        return Err(PlanError::StackCountMismatch {
+            start: start.len(),
+            target: target.len(),
+        });
+    }
+
+    if sorted_crates(start) != sorted_crates(target) {
+        return Err(PlanError::CrateMismatch);
+    }
+
+    if start == target {
+        return Ok(vec![]);
+    }
+
+    if start.len() < 3 {
+        return Err(PlanError::TooFewStacks);
+    }
+
+    let mut state = start.to_vec();
+    let mut instructions: Vec<Instruction> = vec![];
+
+    while state != target {
+        let layout = Layout::new(&state, target);
+
+        let next = layout
+            .direct_move(crane)
+            .or_else(|| layout.dig_move())
+            .unwrap_or_else(|| layout.clear_move());
+
+        crane
+            .apply(&mut state, &next)
+            .expect("planned moves only take crates that are there");
+        instructions.push(next);
+    }
+
+    Ok(instructions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Stack> {
+        vec![
+            Stack::from_str("ZN"),
+            Stack::from_str("MCD"),
+            Stack::from_str("P"),
+        ]
+    }
+
+    #[test]
+    fn plan_replays_to_target() {
+        let start = sample();
+        let target = vec![
+            Stack::from_str("C"),
+            Stack::from_str("M"),
+            Stack::from_str("PDNZ"),
+        ];
+
+        for crane in [Crane::CrateMover9000, Crane::CrateMover9001] {
+            let instructions = plan(&start, &target, crane).unwrap();
+
+            assert_eq!(crane.run(&start, &instructions), Ok(target.clone()));
+
+            let back = plan(&target, &start, crane).unwrap();
+
+            assert_eq!(crane.run(&target, &back), Ok(start.clone()));
+        }
+    }
+
+    #[test]
+    fn crate_mover_9001_moves_groups_in_one_go() {
+        let start = vec![Stack::from_str("ABC"), Stack::new(), Stack::new()];
+        let target = vec![Stack::new(), Stack::new(), Stack::from_str("ABC")];
+
+        let instructions = plan(&start, &target, Crane::CrateMover9001).unwrap();
+
+        assert_eq!(
+            instructions,
+            vec![Instruction {
+                m: 3,
+                src: 0,
+                dest: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn rejects_layouts_with_different_crates() {
+        let target = vec![
+            Stack::from_str("ZN"),
+            Stack::from_str("MCX"),
+            Stack::from_str("P"),
+        ];
+
+        assert_eq!(
+            plan(&sample(), &target, Crane::CrateMover9000),
+            Err(PlanError::CrateMismatch)
+        );
+        assert_eq!(
+            plan(&sample(), &sample()[..2], Crane::CrateMover9000),
+            Err(PlanError::StackCountMismatch {
+                start: 3,
+                target: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,45 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_four::{find_any_overlaps, find_complete_overlaps, group_into_assignments, Assignments};
+use generator::generate;
+
+const SAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+fn parse(input: &str) -> Vec<Assignments> {
+    input
+        .lines()
+        .map(|line| group_into_assignments(line).unwrap())
+        .collect()
+}
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let assignments = parse(input);
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+    group.bench_function("part one", |b| {
+        b.iter(|| find_complete_overlaps(black_box(&assignments)))
+    });
+    group.bench_function("part two", |b| {
+        b.iter(|| find_any_overlaps(black_box(&assignments)))
+    });
+
+    group.finish();
+}
+
+fn day_four(c: &mut Criterion) {
+    bench(c, "day_four/sample", SAMPLE);
+    bench(c, "day_four/scaled", &generate(10_000, 2022));
+}
+
+criterion_group!(benches, day_four);
+criterion_main!(benches);
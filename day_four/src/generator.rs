@@ -0,0 +1,19 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+fn range(rng: &mut ChaCha8Rng) -> String {
+    let start = rng.gen_range(1..=99);
+    let end = rng.gen_range(start..=99);
+
+    format!("{}-{}", start, end)
+}
+
+// `pairs` section assignment pairs with sections numbered 1 to 99
+pub fn generate(pairs: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    (0..pairs)
+        .map(|_| format!("{},{}", range(&mut rng), range(&mut rng)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod generator;
+
+use std::{collections::HashSet, error::Error, fmt};
+
+use common::normalize::normalize;
+use common::Solution;
+
+pub type Assignments = [HashSet<i32>; 2];
+
+// Sections are numbered 1 to 99 in practice. The cap keeps a malformed
+// range like `1-2000000000` from filling memory.
+const MAX_SECTIONS: i32 = 100_000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AssignmentError {
+    // Expected `<start>-<end>,<start>-<end>`
+    Invalid(String),
+    TooManySections(String),
+}
+
+impl fmt::Display for AssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignmentError::Invalid(s) => write!(f, "invalid assignment {:?}", s),
+            AssignmentError::TooManySections(s) => {
+                write!(f, "{:?} covers more than {} sections", s, MAX_SECTIONS)
+            }
+        }
+    }
+}
+
+impl Error for AssignmentError {}
+
+pub fn string_to_range_vector(s: &str) -> Result<HashSet<i32>, AssignmentError> {
+    let [start, end]: [i32; 2] = s
+        .split("-")
+        .take(2)
+        .filter_map(|n| n.parse::<i32>().ok())
+        .collect::<Vec<i32>>()
+        .try_into()
+        .map_err(|_| AssignmentError::Invalid(s.to_string()))?;
+
+    // A reversed range would be an empty assignment, which every other
+    // assignment "contains" without overlapping it
+    if start > end {
+        return Err(AssignmentError::Invalid(s.to_string()));
+    }
+
+    if end.saturating_sub(start) >= MAX_SECTIONS {
+        return Err(AssignmentError::TooManySections(s.to_string()));
+    }
+
+    Ok((start..=end).collect::<HashSet<i32>>())
+}
+
+pub fn group_into_assignments(s: &str) -> Result<Assignments, AssignmentError> {
+    s.split(',')
+        .take(2)
+        .map(string_to_range_vector)
+        .collect::<Result<Vec<HashSet<i32>>, AssignmentError>>()?
+        .try_into()
+        .map_err(|_| AssignmentError::Invalid(s.to_string()))
+}
+
+pub fn find_complete_overlaps(assignments: &[Assignments]) -> i32 {
+    assignments
+        .iter()
+        .filter(|assignment| {
+            let [left, right] = assignment;
+
+            left.is_subset(right) || right.is_subset(left)
+        })
+        .count() as i32
+}
+
+pub fn find_any_overlaps(assignments: &[Assignments]) -> i32 {
+    assignments
+        .iter()
+        .filter(|assignment| {
+            let [left, right] = assignment;
+
+            let intersection = left.intersection(right);
+
+            intersection.into_iter().count() > 0
+        })
+        .count() as i32
+}
+
+fn parse(input: &str) -> Result<Vec<Assignments>, AssignmentError> {
+    input.lines().map(group_into_assignments).collect()
+}
+
+pub struct DayFour;
+
+impl Solution for DayFour {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(find_complete_overlaps(&parse(&normalize(input))?).to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(find_any_overlaps(&parse(&normalize(input))?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn range_from_string() {
+        let out = string_to_range_vector("1-5").unwrap();
+
+        assert_eq!(out, HashSet::from([1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn rejects_malformed_assignments() {
+        assert_eq!(
+            group_into_assignments("1-5"),
+            Err(AssignmentError::Invalid("1-5".to_string()))
+        );
+        assert_eq!(
+            group_into_assignments("1-5,x-3"),
+            Err(AssignmentError::Invalid("x-3".to_string()))
+        );
+        assert_eq!(
+            group_into_assignments("1-5,1-2000000000"),
+            Err(AssignmentError::TooManySections("1-2000000000".to_string()))
+        );
+        assert_eq!(
+            group_into_assignments("5-1,2-3"),
+            Err(AssignmentError::Invalid("5-1".to_string()))
+        );
+    }
+
+    fn range() -> impl Strategy<Value = String> {
+        (1i32..100, 0i32..20).prop_map(|(start, len)| format!("{}-{}", start, start + len))
+    }
+
+    proptest! {
+        #[test]
+        fn complete_overlaps_are_also_any_overlaps(
+            pairs in prop::collection::vec((range(), range()), 0..50)
+        ) {
+            let assignments = pairs
+                .iter()
+                .map(|(left, right)| group_into_assignments(&format!("{},{}", left, right)))
+                .collect::<Result<Vec<Assignments>, _>>()
+                .unwrap();
+
+            for assignment in &assignments {
+                let pair = std::slice::from_ref(assignment);
+
+                prop_assert!(find_complete_overlaps(pair) <= find_any_overlaps(pair));
+            }
+
+            prop_assert!(find_complete_overlaps(&assignments) <= find_any_overlaps(&assignments));
+        }
+    }
+
+    #[test]
+    fn generates_parsable_pairs() {
+        let input = generate(300, 5);
+
+        let assignments = input
+            .lines()
+            .map(group_into_assignments)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(assignments.len(), 300);
+    }
+}
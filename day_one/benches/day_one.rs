@@ -0,0 +1,45 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_one::report::parse_elves;
+use day_one::top_elves::track_top_elves;
+use generator::generate;
+
+const SAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| b.iter(|| parse_elves(black_box(input))));
+    group.bench_function("part one", |b| {
+        b.iter(|| track_top_elves(black_box(input.as_bytes()), 1))
+    });
+    group.bench_function("part two", |b| {
+        b.iter(|| track_top_elves(black_box(input.as_bytes()), 3))
+    });
+
+    group.finish();
+}
+
+fn day_one(c: &mut Criterion) {
+    bench(c, "day_one/sample", SAMPLE);
+    bench(c, "day_one/scaled", &generate(2500, 2022));
+}
+
+criterion_group!(benches, day_one);
+criterion_main!(benches);
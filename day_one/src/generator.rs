@@ -0,0 +1,19 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// An inventory list of `elves` elves, each carrying one to six snacks
+pub fn generate(elves: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    (0..elves)
+        .map(|_| {
+            let items = rng.gen_range(1..=6);
+
+            (0..items)
+                .map(|_| rng.gen_range(1000..=60000).to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
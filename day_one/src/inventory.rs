@@ -0,0 +1,194 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+#[derive(Debug)]
+pub enum InventoryError {
+    Io(io::Error),
+    // A line that is neither blank nor a number of calories
+    Malformed { line: usize, content: String },
+    // The elf's total no longer fits in a u64
+    Overflow { line: usize },
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InventoryError::Io(err) => write!(f, "unable to read inventory: {}", err),
+            InventoryError::Malformed { line, content } => {
+                write!(f, "line {}: expected calories, found {:?}", line, content)
+            }
+            InventoryError::Overflow { line } => {
+                write!(f, "line {}: total calories overflow", line)
+            }
+        }
+    }
+}
+
+impl Error for InventoryError {}
+
+impl From<io::Error> for InventoryError {
+    fn from(err: io::Error) -> Self {
+        InventoryError::Io(err)
+    }
+}
+
+// Reads elf inventories one at a time. Inventories are separated by one or
+// more blank lines, and both LF and CRLF line endings are accepted.
+pub struct Inventories<R: BufRead> {
+    reader: R,
+    line: usize,
+    buffer: String,
+    done: bool,
+    read: usize,
+    empty: usize,
+}
+
+impl<R: BufRead> Inventories<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: 0,
+            buffer: String::new(),
+            done: false,
+            read: 0,
+            empty: 0,
+        }
+    }
+
+    // Empty inventories read so far. They aren't yielded, but every blank
+    // line after the one that ends an inventory is an elf carrying nothing.
+    // Blank lines before the first inventory or after the last don't count.
+    pub fn empty(&self) -> usize {
+        self.empty
+    }
+
+    fn next_inventory(&mut self) -> Result<Option<(usize, Vec<u64>, u64)>, InventoryError> {
+        let mut items: Vec<u64> = vec![];
+        let mut total: u64 = 0;
+        let mut skipped = 0;
+
+        loop {
+            self.buffer.clear();
+
+            if self.reader.read_line(&mut self.buffer)? == 0 {
+                self.done = true;
+                break;
+            }
+
+            self.line += 1;
+
+            let line = self.buffer.trim_end_matches(['\n', '\r']);
+
+            if line.trim().is_empty() {
+                if items.is_empty() {
+                    skipped += 1;
+                    continue;
+                }
+
+                break;
+            }
+
+            let calories = line.parse::<u64>().map_err(|_| InventoryError::Malformed {
+                line: self.line,
+                content: line.to_string(),
+            })?;
+
+            total = total
+                .checked_add(calories)
+                .ok_or(InventoryError::Overflow { line: self.line })?;
+
+            if items.is_empty() && self.read > 0 {
+                self.empty += skipped;
+            }
+
+            items.push(calories);
+        }
+
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        self.read += 1;
+
+        Ok(Some((self.read + self.empty, items, total)))
+    }
+}
+
+impl<R: BufRead> Iterator for Inventories<R> {
+    // An elf's 1-based position, counting the empty inventories before it,
+    // and its items along with their total
+    type Item = Result<(usize, Vec<u64>, u64), InventoryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let next = self.next_inventory();
+
+        if next.is_err() {
+            self.done = true;
+        }
+
+        next.transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn totals(input: &str) -> Result<Vec<u64>, InventoryError> {
+        Inventories::new(input.as_bytes())
+            .map(|inventory| inventory.map(|(_, _, total)| total))
+            .collect()
+    }
+
+    #[test]
+    fn handles_crlf_and_repeated_blank_lines() {
+        let input = "\r\n1000\r\n2000\r\n\r\n\r\n\r\n3000\r\n  \r\n4000\r\n\r\n";
+
+        assert_eq!(totals(input).unwrap(), vec![3000, 3000, 4000]);
+    }
+
+    #[test]
+    fn counts_empty_inventories_between_elves() {
+        let input = "\n\n1000\n\n\n2000\r\n\r\n \r\n\r\n3000\n\n4000\n\n\n";
+        let mut inventories = Inventories::new(input.as_bytes());
+
+        let positions = inventories
+            .by_ref()
+            .map(|inventory| inventory.unwrap().0)
+            .collect::<Vec<usize>>();
+
+        // One between 1000 and 2000, two between 2000 and 3000
+        assert_eq!(positions, vec![1, 3, 6, 7]);
+        assert_eq!(inventories.empty(), 3);
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        let err = totals("1000\n2000\n\n30O0\n").unwrap_err();
+
+        assert!(matches!(
+            err,
+            InventoryError::Malformed { line: 4, ref content } if content == "30O0"
+        ));
+        assert_eq!(err.to_string(), "line 4: expected calories, found \"30O0\"");
+    }
+
+    #[test]
+    fn totals_beyond_i32_and_overflow() {
+        assert_eq!(totals("3000000000\n3000000000").unwrap(), vec![6000000000]);
+
+        let input = format!("{}\n1\n", u64::MAX);
+
+        assert!(matches!(
+            totals(&input),
+            Err(InventoryError::Overflow { line: 2 })
+        ));
+    }
+}
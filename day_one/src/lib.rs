@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod generator;
+pub mod inventory;
+pub mod report;
+pub mod stats;
+pub mod top_elves;
+
+use std::error::Error;
+#[cfg(test)]
+use std::iter::Take;
+#[cfg(test)]
+use std::slice::Iter;
+
+use common::normalize::normalize;
+use common::Solution;
+
+use inventory::{Inventories, InventoryError};
+
+pub fn get_sorted_total_calories_by_elf(input: String) -> Result<Vec<u64>, InventoryError> {
+    get_sorted_totals_and_empty(&input).map(|(calories_by_elf, _)| calories_by_elf)
+}
+
+// The totals of the elves carrying something, largest first, along with how
+// many elves carry nothing at all
+pub fn get_sorted_totals_and_empty(input: &str) -> Result<(Vec<u64>, usize), InventoryError> {
+    let mut inventories = Inventories::new(input.as_bytes());
+
+    let mut calories_by_elf = inventories
+        .by_ref()
+        .map(|inventory| inventory.map(|(_, _, total)| total))
+        .collect::<Result<Vec<u64>, InventoryError>>()?;
+
+    calories_by_elf.sort_by(|a, b| b.cmp(a));
+
+    Ok((calories_by_elf, inventories.empty()))
+}
+
+#[cfg(test)]
+fn get_first_n_elves(calories_by_elf: &[u64], n: usize) -> Take<Iter<'_, u64>> {
+    calories_by_elf.iter().take(n)
+}
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let top = top_elves::track_top_elves(normalize(input).as_bytes(), 1)?;
+
+        Ok(top.iter().sum::<u64>().to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let top = top_elves::track_top_elves(normalize(input).as_bytes(), 3)?;
+
+        Ok(top.iter().sum::<u64>().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::generate;
+    use crate::report::parse_elves;
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn sorted_calories_by_elf() {
+        let output = get_sorted_total_calories_by_elf(INPUT.to_string()).unwrap();
+
+        assert_eq!(output, vec!(24000, 11000, 10000, 6000, 4000))
+    }
+
+    #[test]
+    fn top_elf() {
+        let output = get_sorted_total_calories_by_elf(INPUT.to_string()).unwrap();
+
+        assert_eq!(get_first_n_elves(&output, 1).sum::<u64>(), 24000)
+    }
+
+    #[test]
+    fn top_three_elves() {
+        let output = get_sorted_total_calories_by_elf(INPUT.to_string()).unwrap();
+
+        assert_eq!(get_first_n_elves(&output, 3).sum::<u64>(), 45000)
+    }
+
+    #[test]
+    fn consecutive_blank_lines_are_empty_elves() {
+        let input = INPUT.replace("\n\n4000", "\n\n\n\n4000");
+
+        let (output, empty) = get_sorted_totals_and_empty(&input).unwrap();
+
+        assert_eq!(output, vec!(24000, 11000, 10000, 6000, 4000));
+        assert_eq!(empty, 2);
+    }
+
+    #[test]
+    fn windows_edited_input() {
+        let input = format!("\u{feff}{}\r\n", INPUT.replace('\n', "\r\n"));
+
+        assert_eq!(DayOne.part_one(&input).unwrap(), "24000");
+        assert_eq!(DayOne.part_two(&input).unwrap(), "45000");
+    }
+
+    #[test]
+    fn generates_the_requested_number_of_elves() {
+        let input = generate(250, 7);
+
+        assert_eq!(parse_elves(&input).unwrap().len(), 250);
+    }
+}
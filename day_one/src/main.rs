@@ -1,102 +1,89 @@
-use std::{fs};
-use std::iter::Take;
-use std::slice::Iter;
-
-fn get_sorted_total_calories_by_elf (input: String) -> Vec<i32> {
-
-    let mut temp: i32 = 0;
+use std::{env, iter};
+
+use common::{
+    inputs::{open_input, read_input},
+    local_input,
+};
+
+use day_one::get_sorted_totals_and_empty;
+use day_one::report::{format_json, format_report, parse_elves, rank_elves};
+use day_one::stats::{format_stats, histogram, summarize};
+use day_one::top_elves::track_top_elves;
+
+// `report [--json] [--top <n>]` lists the top elves along with what they carry
+fn print_report(args: &[String]) {
+    let json = args.iter().any(|arg| arg == "--json");
+    let n = args
+        .iter()
+        .position(|arg| arg == "--top")
+        .and_then(|i| args.get(i + 1))
+        .map(|n| n.parse::<usize>().expect("Invalid number of elves"))
+        .unwrap_or(3);
 
-    let mut calories_by_elf: Vec<i32> = vec!();
+    let input = read_input(local_input!(), 2022, 1)
+        .unwrap_or_else(|err| panic!("Unable to read input: {}", err));
 
-    for line in input.lines() {
-        let calories = line.parse::<i32>();
+    let elves = parse_elves(&input).unwrap_or_else(|err| panic!("Invalid inventory: {}", err));
+    let rankings = rank_elves(&elves, n);
 
-        match calories {
-            Ok(calories) => {
-                temp += calories;
-            },
-            Err(_) => {
-                calories_by_elf.push(temp);
-                temp = 0;
-            }
-        }
+    if json {
+        println!("{}", format_json(&rankings));
+    } else {
+        println!("{}", format_report(&rankings));
     }
-
-    calories_by_elf.push(temp);
-
-    calories_by_elf.sort_by(|a, b| b.cmp(a));
-
-    calories_by_elf
-}
-
-fn get_first_n_elves (calories_by_elf: &Vec<i32>, n: usize) -> Take<Iter<'_, i32>> {
-    calories_by_elf
-        .iter()
-        .take(n)
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Unable to open input");
-
-    let mut calories_by_elf = get_sorted_total_calories_by_elf(input);
+// `stats` summarizes the totals of every elf
+fn print_stats() {
+    let input = read_input(local_input!(), 2022, 1)
+        .unwrap_or_else(|err| panic!("Unable to read input: {}", err));
 
-    let top_elf = get_first_n_elves(&calories_by_elf, 1);
-    let top_three_elves = get_first_n_elves(&calories_by_elf, 3);
+    let (mut calories_by_elf, empty) = get_sorted_totals_and_empty(&input)
+        .unwrap_or_else(|err| panic!("Invalid inventory: {}", err));
 
-    // Part One - Total calories from the elf with the most calories
-    let max = top_elf.sum::<i32>();
-
-    // Part Two - Total calories from the top 3 elves with the most calories
-    let max_of_three = top_three_elves.sum::<i32>();
+    // Elves carrying nothing have a total of 0, which sorts last
+    calories_by_elf.extend(iter::repeat_n(0, empty));
 
-    println!("Part One - Total calories from the elf with the most calories: {}", max);
-    println!("Part Two - Total calories from the top 3 elves: {}", max_of_three);
+    match summarize(&calories_by_elf, empty) {
+        Some(stats) => println!("{}", format_stats(&stats, &histogram(&calories_by_elf, 10))),
+        None => println!("no elves"),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000";
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
 
-    #[test]
-    fn sorted_calories_by_elf () {
-        let output = get_sorted_total_calories_by_elf(INPUT.to_string());
+    if args.first().map(String::as_str) == Some("report") {
+        print_report(&args[1..]);
+        return;
+    }
 
-        assert_eq!(output, vec!(24000, 11000, 10000, 6000, 4000))
+    if args.first().map(String::as_str) == Some("stats") {
+        print_stats();
+        return;
     }
 
-    #[test]
-    fn top_elf () {
-        let output = get_sorted_total_calories_by_elf(INPUT.to_string());
+    let input = open_input(local_input!(), 2022, 1)
+        .unwrap_or_else(|err| panic!("Unable to read input: {}", err));
 
-        assert_eq!(
-            get_first_n_elves(&output, 1).sum::<i32>(),
-            24000
-        )
-    }
+    let top_three_elves =
+        track_top_elves(input, 3).unwrap_or_else(|err| panic!("Invalid inventory: {}", err));
 
-    #[test]
-    fn top_three_elves() {
-        let output = get_sorted_total_calories_by_elf(INPUT.to_string());
+    let top_elf = top_three_elves.iter().take(1);
+    let top_three_elves = top_three_elves.iter();
 
-        assert_eq!(
-            get_first_n_elves(&output, 3).sum::<i32>(),
-            45000
-        )
-    }
+    // Part One - Total calories from the elf with the most calories
+    let max = top_elf.sum::<u64>();
 
-}
\ No newline at end of file
+    // Part Two - Total calories from the top 3 elves with the most calories
+    let max_of_three = top_three_elves.sum::<u64>();
+
+    println!(
+        "Part One - Total calories from the elf with the most calories: {}",
+        max
+    );
+    println!(
+        "Part Two - Total calories from the top 3 elves: {}",
+        max_of_three
+    );
+}
@@ -0,0 +1,192 @@
+use std::cmp::Reverse;
+
+use crate::inventory::{Inventories, InventoryError};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Elf {
+    // 1-based position of the elf in the input, counting elves carrying
+    // nothing as the stats do
+    pub index: usize,
+    pub items: Vec<u64>,
+    pub total: u64,
+}
+
+// Same grouping rules as `get_sorted_total_calories_by_elf`, but keeping
+// track of who carries what
+pub fn parse_elves(input: &str) -> Result<Vec<Elf>, InventoryError> {
+    Inventories::new(input.as_bytes())
+        .map(|inventory| {
+            inventory.map(|(index, items, total)| Elf {
+                index,
+                items,
+                total,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Ranking<'a> {
+    // Elves with the same total share a rank, and the next rank is skipped
+    pub rank: usize,
+    pub tied: bool,
+    pub elf: &'a Elf,
+}
+
+// Every elf ranked `n` or better, so an elf tied for the last place is never
+// left out. Elves with the same total keep their input order.
+pub fn rank_elves(elves: &[Elf], n: usize) -> Vec<Ranking<'_>> {
+    let mut sorted = elves.iter().collect::<Vec<&Elf>>();
+
+    sorted.sort_by_key(|elf| Reverse(elf.total));
+
+    let mut rankings: Vec<Ranking> = vec![];
+
+    for (i, elf) in sorted.iter().enumerate() {
+        let rank = match rankings.last() {
+            Some(previous) if previous.elf.total == elf.total => previous.rank,
+            _ => i + 1,
+        };
+
+        if rank > n {
+            break;
+        }
+
+        let tied = [i.checked_sub(1), Some(i + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|j| sorted.get(j))
+            .any(|other| other.total == elf.total);
+
+        rankings.push(Ranking { rank, tied, elf });
+    }
+
+    rankings
+}
+
+pub fn format_report(rankings: &[Ranking]) -> String {
+    rankings
+        .iter()
+        .map(|Ranking { rank, tied, elf }| {
+            let items = elf
+                .items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(" + ");
+
+            format!(
+                "{}{}. elf {}: {} calories ({})",
+                if *tied { "=" } else { "" },
+                rank,
+                elf.index,
+                elf.total,
+                items
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn format_json(rankings: &[Ranking]) -> String {
+    let entries = rankings
+        .iter()
+        .map(|Ranking { rank, tied, elf }| {
+            let items = elf
+                .items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+
+            format!(
+                "{{\"rank\":{},\"tied\":{},\"elf\":{},\"total\":{},\"items\":[{}]}}",
+                rank, tied, elf.index, elf.total, items
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+
+    format!("[{}]", entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn keeps_elf_identity_and_items() {
+        let elves = parse_elves(INPUT).unwrap();
+
+        assert_eq!(elves.len(), 5);
+        assert_eq!(
+            elves[3],
+            Elf {
+                index: 4,
+                items: vec![7000, 8000, 9000],
+                total: 24000
+            }
+        );
+    }
+
+    #[test]
+    fn ranks_top_three_with_ties() {
+        let elves = parse_elves("6000\n\n1000\n\n4000\n2000\n\n5000\n\n3000\n3000").unwrap();
+        let rankings = rank_elves(&elves, 3);
+
+        let summary = rankings
+            .iter()
+            .map(|r| (r.rank, r.tied, r.elf.index))
+            .collect::<Vec<(usize, bool, usize)>>();
+
+        // Elves 1, 3 and 5 all carry 6000, so elf 4 only comes fourth
+        assert_eq!(summary, vec![(1, true, 1), (1, true, 3), (1, true, 5)]);
+
+        let rankings = rank_elves(&elves, 4);
+
+        assert_eq!(rankings.last().map(|r| (r.rank, r.elf.index)), Some((4, 4)));
+    }
+
+    #[test]
+    fn formats_report_and_json() {
+        let elves = parse_elves(INPUT).unwrap();
+        let rankings = rank_elves(&elves, 2);
+
+        assert_eq!(
+            format_report(&rankings),
+            "1. elf 4: 24000 calories (7000 + 8000 + 9000)\n2. elf 3: 11000 calories (5000 + 6000)"
+        );
+        assert_eq!(
+            format_json(&rankings),
+            "[{\"rank\":1,\"tied\":false,\"elf\":4,\"total\":24000,\"items\":[7000,8000,9000]},\
+             {\"rank\":2,\"tied\":false,\"elf\":3,\"total\":11000,\"items\":[5000,6000]}]"
+        );
+    }
+
+    #[test]
+    fn numbers_elves_counting_empty_ones() {
+        let elves = parse_elves("100\n\n\n300\n\n200").unwrap();
+        let rankings = rank_elves(&elves, 1);
+
+        assert_eq!(format_report(&rankings), "1. elf 3: 300 calories (300)");
+        assert_eq!(
+            elves.iter().map(|elf| elf.index).collect::<Vec<usize>>(),
+            vec![1, 3, 4]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+const PERCENTILES: [u8; 4] = [25, 75, 90, 99];
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    // Population standard deviation
+    pub std_dev: f64,
+    pub percentiles: Vec<(u8, u64)>,
+    // Elves without any items, as opposed to items adding up to 0
+    pub empty: usize,
+}
+
+// Nearest-rank percentile of an ascending list
+fn percentile(ascending: &[u64], p: u8) -> u64 {
+    let rank = (p as usize * ascending.len()).div_ceil(100).max(1);
+
+    ascending[rank - 1]
+}
+
+// Takes the totals of every elf, largest first, with the `empty` elves among
+// them as totals of 0
+pub fn summarize(sorted_totals: &[u64], empty: usize) -> Option<Stats> {
+    let ascending = sorted_totals.iter().rev().copied().collect::<Vec<u64>>();
+
+    let count = ascending.len();
+    let (min, max) = (*ascending.first()?, *ascending.last()?);
+
+    let mean = ascending.iter().map(|t| *t as f64).sum::<f64>() / count as f64;
+
+    let median = if count % 2 == 0 {
+        (ascending[count / 2 - 1] as f64 + ascending[count / 2] as f64) / 2.0
+    } else {
+        ascending[count / 2] as f64
+    };
+
+    let variance = ascending
+        .iter()
+        .map(|t| (*t as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count as f64;
+
+    Some(Stats {
+        count,
+        min,
+        max,
+        mean,
+        median,
+        std_dev: variance.sqrt(),
+        percentiles: PERCENTILES
+            .iter()
+            .map(|p| (*p, percentile(&ascending, *p)))
+            .collect(),
+        empty,
+    })
+}
+
+// Splits the range of totals into `buckets` equally wide buckets, returning
+// the lower bound of each bucket along with how many totals fall into it.
+// No buckets, or no totals, make an empty histogram.
+pub fn histogram(sorted_totals: &[u64], buckets: usize) -> Vec<(u64, usize)> {
+    let (Some(max), Some(min)) = (sorted_totals.first(), sorted_totals.last()) else {
+        return vec![];
+    };
+
+    if buckets == 0 {
+        return vec![];
+    }
+
+    let width = (max - min) / buckets as u64 + 1;
+
+    let mut counts = vec![0; buckets];
+
+    for total in sorted_totals {
+        counts[((total - min) / width) as usize] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (min + i as u64 * width, count))
+        .collect()
+}
+
+pub fn format_stats(stats: &Stats, histogram: &[(u64, usize)]) -> String {
+    let mut lines = vec![
+        format!("elves: {} ({} empty)", stats.count, stats.empty),
+        format!("min: {}", stats.min),
+        format!("max: {}", stats.max),
+        format!("mean: {:.2}", stats.mean),
+        format!("median: {:.1}", stats.median),
+        format!("standard deviation: {:.2}", stats.std_dev),
+    ];
+
+    for (p, value) in &stats.percentiles {
+        lines.push(format!("p{}: {}", p, value));
+    }
+
+    let tallest = histogram.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    let width = histogram
+        .iter()
+        .map(|(lower, _)| lower.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    for (lower, count) in histogram {
+        let bar = "#".repeat((count * 40).div_ceil(tallest.max(1)));
+
+        lines.push(format!("{:>width$} | {:>5} {}", lower, count, bar));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_sample_totals() {
+        let stats = summarize(&[24000, 11000, 10000, 6000, 4000], 0).unwrap();
+
+        assert_eq!(stats.count, 5);
+        assert_eq!((stats.min, stats.max), (4000, 24000));
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert!((stats.std_dev - 6985.70).abs() < 0.01);
+        assert_eq!(
+            stats.percentiles,
+            vec![(25, 6000), (75, 11000), (90, 24000), (99, 24000)]
+        );
+        assert_eq!(stats.empty, 0);
+    }
+
+    #[test]
+    fn counts_empty_inventories_and_even_medians() {
+        let stats = summarize(&[3000, 1000, 0, 0], 2).unwrap();
+
+        assert_eq!(stats.median, 500.0);
+        assert_eq!(stats.empty, 2);
+
+        assert_eq!(summarize(&[], 0), None);
+    }
+
+    #[test]
+    fn buckets_totals_into_histogram() {
+        let histogram = histogram(&[24000, 11000, 10000, 6000, 4000], 4);
+
+        assert_eq!(
+            histogram,
+            vec![(4000, 2), (9001, 2), (14002, 0), (19003, 1)]
+        );
+    }
+
+    #[test]
+    fn no_buckets_make_an_empty_histogram() {
+        assert_eq!(histogram(&[24000, 4000], 0), vec![]);
+        assert_eq!(histogram(&[], 4), vec![]);
+    }
+}
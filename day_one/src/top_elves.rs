@@ -0,0 +1,117 @@
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use crate::inventory::{Inventories, InventoryError};
+
+// Keeps the `n` largest totals seen so far in a min-heap, so the smallest of
+// them is always the one to evict
+pub struct TopElves {
+    n: usize,
+    heap: BinaryHeap<Reverse<u64>>,
+}
+
+impl TopElves {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    pub fn add(&mut self, total: u64) {
+        if self.heap.len() < self.n {
+            self.heap.push(Reverse(total));
+        } else if self.heap.peek().is_some_and(|Reverse(min)| total > *min) {
+            self.heap.pop();
+            self.heap.push(Reverse(total));
+        }
+    }
+
+    // Largest first, like `get_sorted_total_calories_by_elf`
+    pub fn into_sorted(self) -> Vec<u64> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(total)| total)
+            .collect()
+    }
+}
+
+// Reads one inventory at a time, holding on to the top `n` totals only
+pub fn track_top_elves<R: BufRead>(reader: R, n: usize) -> Result<Vec<u64>, InventoryError> {
+    let mut top = TopElves::new(n);
+
+    for inventory in Inventories::new(reader) {
+        let (_, _, total) = inventory?;
+
+        top.add(total);
+    }
+
+    Ok(top.into_sorted())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_sorted_total_calories_by_elf;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn keeps_only_the_top_n() {
+        let top = track_top_elves(INPUT.as_bytes(), 3).unwrap();
+
+        assert_eq!(top, vec![24000, 11000, 10000]);
+    }
+
+    #[test]
+    fn matches_sorting_every_elf() {
+        let sorted = get_sorted_total_calories_by_elf(INPUT.to_string()).unwrap();
+
+        for n in 0..=sorted.len() + 1 {
+            let top = track_top_elves(INPUT.as_bytes(), n).unwrap();
+
+            assert_eq!(top, sorted.iter().take(n).copied().collect::<Vec<u64>>());
+        }
+    }
+
+    // Elves carrying one to five items each, written out as an inventory list
+    fn inventories() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop::collection::vec(1u64..100_000, 1..5), 0..50).prop_map(|elves| {
+            elves
+                .iter()
+                .map(|items| {
+                    items
+                        .iter()
+                        .map(u64::to_string)
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                })
+                .collect::<Vec<String>>()
+                .join("\n\n")
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn top_n_is_a_prefix_of_every_elf_sorted(input in inventories(), n in 0usize..10) {
+            let sorted = get_sorted_total_calories_by_elf(input.clone()).unwrap();
+            let top = track_top_elves(input.as_bytes(), n).unwrap();
+
+            prop_assert_eq!(top, sorted.into_iter().take(n).collect::<Vec<u64>>());
+        }
+    }
+}
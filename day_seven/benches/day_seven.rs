@@ -0,0 +1,61 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_seven::{part_one, part_two, FileSystem};
+use generator::generate;
+
+const SAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060091 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let sizes = FileSystem::recreate_crom_terminal(input)
+        .unwrap()
+        .calculate_dir_sizes()
+        .unwrap();
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| {
+        b.iter(|| FileSystem::recreate_crom_terminal(black_box(input)))
+    });
+    group.bench_function("dir sizes", |b| {
+        let file_system = FileSystem::recreate_crom_terminal(input).unwrap();
+
+        b.iter(|| black_box(&file_system).calculate_dir_sizes())
+    });
+    group.bench_function("part one", |b| b.iter(|| part_one(black_box(&sizes))));
+    group.bench_function("part two", |b| b.iter(|| part_two(black_box(&sizes))));
+
+    group.finish();
+}
+
+fn day_seven(c: &mut Criterion) {
+    bench(c, "day_seven/sample", SAMPLE);
+    bench(c, "day_seven/scaled", &generate(2000, 2022));
+}
+
+criterion_group!(benches, day_seven);
+criterion_main!(benches);
@@ -0,0 +1,86 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Directory names are the directory's number in base 26, so no two clash
+fn dir_name(mut i: usize) -> String {
+    let mut name = vec![];
+
+    loop {
+        name.push(b'a' + (i % 26) as u8);
+        i /= 26;
+
+        if i == 0 {
+            break;
+        }
+    }
+
+    String::from_utf8(name).unwrap()
+}
+
+fn file_name(rng: &mut ChaCha8Rng, i: usize) -> String {
+    let extension = ["", ".txt", ".dat", ".log", ".lst"][rng.gen_range(0..5)];
+
+    format!("f{}{}", i, extension)
+}
+
+struct Dir {
+    children: Vec<usize>,
+    files: Vec<(String, u64)>,
+}
+
+fn walk(dirs: &[Dir], i: usize, lines: &mut Vec<String>) {
+    lines.push(format!(
+        "$ cd {}",
+        if i == 0 { "/".to_string() } else { dir_name(i) }
+    ));
+    lines.push("$ ls".to_string());
+
+    for child in &dirs[i].children {
+        lines.push(format!("dir {}", dir_name(*child)));
+    }
+
+    for (name, size) in &dirs[i].files {
+        lines.push(format!("{} {}", size, name));
+    }
+
+    for child in &dirs[i].children {
+        walk(dirs, *child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+// A terminal transcript that lists each of `dirs` directories once, walking
+// the tree depth first from `/`
+pub fn generate(dirs: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let mut tree = (0..dirs.max(1))
+        .map(|_| Dir {
+            children: vec![],
+            files: vec![],
+        })
+        .collect::<Vec<Dir>>();
+
+    for i in 1..tree.len() {
+        let parent = rng.gen_range(0..i);
+
+        tree[parent].children.push(i);
+    }
+
+    for (i, dir) in tree.iter_mut().enumerate() {
+        dir.files = (0..rng.gen_range(0..=4))
+            .map(|j| {
+                (
+                    file_name(&mut rng, i * 5 + j),
+                    rng.gen_range(1000..=300_000),
+                )
+            })
+            .collect();
+    }
+
+    let mut lines = vec![];
+
+    walk(&tree, 0, &mut lines);
+
+    lines.join("\n")
+}
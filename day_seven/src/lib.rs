@@ -0,0 +1,437 @@
+#[cfg(test)]
+mod generator;
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+
+use common::normalize::normalize;
+use common::Solution;
+
+pub const FILE_SYSTEM_MAX: u64 = 70000000;
+pub const REQUIRED_SIZE: u64 = 30000000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TerminalError {
+    // A line that is neither a command we know nor `ls` output
+    UnknownLine { line: usize, content: String },
+    // A directory's total size no longer fits in a u64
+    Overflow { dir: String },
+}
+
+impl fmt::Display for TerminalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TerminalError::UnknownLine { line, content } => {
+                write!(f, "line {}: unexpected {:?}", line, content)
+            }
+            TerminalError::Overflow { dir } => write!(f, "{}: total size overflows", dir),
+        }
+    }
+}
+
+impl Error for TerminalError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SpaceError {
+    NoDirectories,
+    // More is used than the file system holds
+    TooLarge { used: u64 },
+}
+
+impl fmt::Display for SpaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpaceError::NoDirectories => write!(f, "no directories"),
+            SpaceError::TooLarge { used } => write!(
+                f,
+                "{} used is more than the file system's {}",
+                used, FILE_SYSTEM_MAX
+            ),
+        }
+    }
+}
+
+impl Error for SpaceError {}
+
+#[derive(PartialEq, Debug)]
+pub struct File {
+    pub name: String,
+    pub t: FileType,
+    pub size: Option<u64>,
+    pub children: Vec<Rc<RefCell<File>>>,
+    pub parent: Option<Rc<RefCell<File>>>,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum FileType {
+    File,
+    Dir,
+}
+
+impl File {
+    pub fn new_file(name: &str, size: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            size: Some(size),
+            t: FileType::File,
+            children: vec![],
+            parent: None,
+        }
+    }
+
+    pub fn new_dir(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            size: None,
+            t: FileType::Dir,
+            children: vec![],
+            parent: None,
+        }
+    }
+
+    pub fn try_from_str(maybe_file: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut parts = maybe_file.split_whitespace();
+
+        let file_size = parts.next().ok_or("missing file size")?.parse::<u64>()?;
+        let file_name = parts.next().ok_or("missing file name")?;
+
+        if parts.next().is_some() {
+            return Err("unexpected text after the file name".into());
+        }
+
+        Ok(Self::new_file(file_name, file_size))
+    }
+
+    pub fn print(&self) -> String {
+        match self.t {
+            FileType::File => {
+                format!("({} , {})", self.name, self.size.unwrap())
+            }
+            FileType::Dir => {
+                format!(
+                    "[{} , {}]",
+                    self.name,
+                    self.children
+                        .iter()
+                        .map(|tn| tn.borrow().print())
+                        .collect::<Vec<String>>()
+                        .join(" , ")
+                )
+            }
+        }
+    }
+
+    pub fn final_size(&self) -> Result<u64, TerminalError> {
+        match self.t {
+            FileType::File => Ok(self.size.unwrap_or_default()),
+            FileType::Dir => self.children.iter().try_fold(0u64, |total, c| {
+                total
+                    .checked_add(c.borrow().final_size()?)
+                    .ok_or_else(|| TerminalError::Overflow {
+                        dir: self.name.to_owned(),
+                    })
+            }),
+        }
+    }
+}
+
+pub struct FileSystem {
+    pub root: Rc<RefCell<File>>,
+}
+
+impl FileSystem {
+    pub fn recreate_crom_terminal(terminal: &str) -> Result<Self, TerminalError> {
+        let root = Rc::new(RefCell::new(File::new_dir("/")));
+        let mut current = Rc::clone(&root);
+
+        for (i, line) in terminal.lines().enumerate() {
+            let line = line.trim();
+            let parts = line.split_whitespace().collect::<Vec<&str>>();
+
+            match parts[..] {
+                [] | ["$", "ls"] => {}
+                // Directories are created when they're entered
+                ["dir", _] => {}
+                // Like a shell, `cd ..` at the root stays there
+                ["$", "cd", ".."] => {
+                    let parent = current.borrow().parent.as_ref().map(Rc::clone);
+
+                    if let Some(parent) = parent {
+                        current = parent;
+                    }
+                }
+                ["$", "cd", name] => {
+                    let next_dir = Rc::new(RefCell::new(File::new_dir(name)));
+
+                    current.borrow_mut().children.push(Rc::clone(&next_dir));
+
+                    {
+                        let mut mut_next_dir = next_dir.borrow_mut();
+                        mut_next_dir.parent = Some(Rc::clone(&current));
+                    }
+
+                    current = next_dir
+                }
+                _ => {
+                    let file =
+                        File::try_from_str(line).map_err(|_| TerminalError::UnknownLine {
+                            line: i + 1,
+                            content: line.to_string(),
+                        })?;
+
+                    current
+                        .borrow_mut()
+                        .children
+                        .push(Rc::new(RefCell::new(file)))
+                }
+            }
+        }
+
+        Ok(Self { root })
+    }
+
+    // Sizes of every directory, the root first. Directories are listed with
+    // each one after its parent, so adding their totals to their parents' in
+    // reverse sums every subtree without recursing down deep trees.
+    pub fn calculate_dir_sizes(&self) -> Result<Vec<u64>, TerminalError> {
+        let mut dirs: Vec<(Rc<RefCell<File>>, Option<usize>)> = vec![(Rc::clone(&self.root), None)];
+        let mut sizes: Vec<u64> = vec![];
+        let mut i = 0;
+
+        while let Some((dir, _)) = dirs.get(i) {
+            let dir = Rc::clone(dir);
+            let mut size: u64 = 0;
+
+            for child in &dir.borrow().children {
+                let c = child.borrow();
+
+                match c.t {
+                    FileType::Dir => dirs.push((Rc::clone(child), Some(i))),
+                    FileType::File => {
+                        size = size
+                            .checked_add(c.size.unwrap_or_default())
+                            .ok_or_else(|| TerminalError::Overflow {
+                                dir: dir.borrow().name.to_owned(),
+                            })?
+                    }
+                }
+            }
+
+            sizes.push(size);
+            i += 1;
+        }
+
+        for i in (1..dirs.len()).rev() {
+            if let Some(parent) = dirs[i].1 {
+                sizes[parent] =
+                    sizes[parent]
+                        .checked_add(sizes[i])
+                        .ok_or_else(|| TerminalError::Overflow {
+                            dir: dirs[parent].0.borrow().name.to_owned(),
+                        })?;
+            }
+        }
+
+        Ok(sizes)
+    }
+}
+
+// Total size of the directories of at most 100000
+pub fn part_one(sizes: &[u64]) -> u64 {
+    sizes.iter().filter(|size| size <= &&100_000).sum()
+}
+
+// Size of the smallest directory that frees up enough space for the update,
+// given sizes with the root directory first
+pub fn part_two(sizes: &[u64]) -> Result<u64, SpaceError> {
+    let file_system_size = *sizes.first().ok_or(SpaceError::NoDirectories)?;
+
+    let free_space = FILE_SYSTEM_MAX
+        .checked_sub(file_system_size)
+        .ok_or(SpaceError::TooLarge {
+            used: file_system_size,
+        })?;
+
+    let space_left_to_free = REQUIRED_SIZE.saturating_sub(free_space);
+
+    // The root always frees enough, so there is always a smallest
+    Ok(*sizes
+        .iter()
+        .filter(|size| size >= &&space_left_to_free)
+        .min()
+        .unwrap_or(&file_system_size))
+}
+
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let sizes = FileSystem::recreate_crom_terminal(&normalize(input))?.calculate_dir_sizes()?;
+
+        Ok(part_one(&sizes).to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let sizes = FileSystem::recreate_crom_terminal(&normalize(input))?.calculate_dir_sizes()?;
+
+        Ok(part_two(&sizes)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::generate;
+    use proptest::prelude::*;
+
+    // Sizes of every file below `dir`, found by walking the tree rather than
+    // by `final_size`
+    fn file_sizes(dir: &File) -> Vec<u64> {
+        dir.children
+            .iter()
+            .flat_map(|child| {
+                let child = child.borrow();
+
+                match child.t {
+                    FileType::File => vec![child.size.unwrap()],
+                    FileType::Dir => file_sizes(&child),
+                }
+            })
+            .collect()
+    }
+
+    fn dirs(dir: &Rc<RefCell<File>>) -> Vec<Rc<RefCell<File>>> {
+        let mut all = vec![Rc::clone(dir)];
+
+        for child in &dir.borrow().children {
+            if child.borrow().t == FileType::Dir {
+                all.extend(dirs(child));
+            }
+        }
+
+        all
+    }
+
+    #[test]
+    fn malformed_lines_are_not_files() {
+        assert!(File::try_from_str("").is_err());
+        assert!(File::try_from_str("123").is_err());
+        assert!(File::try_from_str("dir a").is_err());
+        assert_eq!(
+            File::try_from_str("584 i").unwrap(),
+            File::new_file("i", 584)
+        );
+
+        assert!(File::try_from_str("584 i j").is_err());
+
+        // Only the root itself is left
+        let file_system = FileSystem::recreate_crom_terminal("$ cd ..\n$ cd ..\n12 a").unwrap();
+
+        assert_eq!(file_system.calculate_dir_sizes(), Ok(vec![12]));
+    }
+
+    #[test]
+    fn rejects_unknown_lines() {
+        assert_eq!(
+            FileSystem::recreate_crom_terminal("hello\nworld").err(),
+            Some(TerminalError::UnknownLine {
+                line: 1,
+                content: "hello".to_string()
+            })
+        );
+        assert_eq!(
+            FileSystem::recreate_crom_terminal("$ ls\ndir a\n$ rm -rf a").err(),
+            Some(TerminalError::UnknownLine {
+                line: 3,
+                content: "$ rm -rf a".to_string()
+            })
+        );
+        assert!(DaySeven.part_one("hello\nworld").is_err());
+    }
+
+    #[test]
+    fn large_totals_do_not_overflow() {
+        let terminal = "$ cd /\n$ ls\n2000000000 a\n2000000000 b\n";
+
+        assert_eq!(DaySeven.part_one(terminal).unwrap(), "0");
+        // More than the file system holds
+        assert_eq!(
+            DaySeven.part_two(terminal).unwrap_err().to_string(),
+            "4000000000 used is more than the file system's 70000000"
+        );
+
+        let terminal = format!("$ cd /\n$ ls\n{} a\n1 b\n", u64::MAX);
+
+        assert_eq!(
+            FileSystem::recreate_crom_terminal(&terminal)
+                .unwrap()
+                .calculate_dir_sizes(),
+            Err(TerminalError::Overflow {
+                dir: "/".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn part_two_needs_the_root_to_fit() {
+        assert_eq!(part_two(&[]), Err(SpaceError::NoDirectories));
+        assert_eq!(
+            part_two(&[FILE_SYSTEM_MAX + 1]),
+            Err(SpaceError::TooLarge {
+                used: FILE_SYSTEM_MAX + 1
+            })
+        );
+        // Already enough space, so the smallest directory will do
+        assert_eq!(part_two(&[10, 3, 7]), Ok(3));
+    }
+
+    proptest! {
+        #[test]
+        fn dir_sizes_are_the_sum_of_their_files(count in 1usize..200, seed: u64) {
+            let transcript = generate(count, seed);
+            let file_system = FileSystem::recreate_crom_terminal(&transcript).unwrap();
+
+            let all = dirs(&file_system.root);
+
+            // `$ cd /` opens a `/` directory inside the root
+            prop_assert_eq!(all.len(), count + 1);
+
+            for dir in &all {
+                let dir = dir.borrow();
+
+                prop_assert_eq!(dir.final_size(), Ok(file_sizes(&dir).iter().sum::<u64>()));
+            }
+
+            let mut sizes = file_system.calculate_dir_sizes().unwrap();
+            let mut expected = all
+                .iter()
+                .map(|dir| dir.borrow().final_size().unwrap())
+                .collect::<Vec<u64>>();
+
+            sizes.sort_unstable();
+            expected.sort_unstable();
+
+            prop_assert_eq!(sizes, expected);
+        }
+    }
+
+    #[test]
+    fn transcripts_rebuild_the_whole_tree() {
+        let transcript = generate(400, 6);
+
+        let file_total: u64 = transcript
+            .lines()
+            .filter_map(|line| line.split_whitespace().next()?.parse::<u64>().ok())
+            .sum();
+
+        let sizes = FileSystem::recreate_crom_terminal(&transcript)
+            .unwrap()
+            .calculate_dir_sizes()
+            .unwrap();
+
+        assert_eq!(sizes.len(), 401);
+        assert_eq!(sizes[0], file_total);
+    }
+}
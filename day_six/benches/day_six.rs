@@ -0,0 +1,38 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_six::decoder::decode;
+use day_six::search::{end_index_of_first_marker, SearchOptions};
+use generator::generate;
+
+const SAMPLE: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let options = SearchOptions::default();
+
+    let mut group = c.benchmark_group(name);
+
+    // The datastream is used as is, so splitting it into messages stands in
+    // for parsing
+    group.bench_function("parse", |b| {
+        b.iter(|| decode(black_box(input.as_bytes()), 14).len())
+    });
+    group.bench_function("part one", |b| {
+        b.iter(|| end_index_of_first_marker(black_box(input), 4, options))
+    });
+    group.bench_function("part two", |b| {
+        b.iter(|| end_index_of_first_marker(black_box(input), 14, options))
+    });
+
+    group.finish();
+}
+
+fn day_six(c: &mut Criterion) {
+    bench(c, "day_six/sample", SAMPLE);
+    bench(c, "day_six/scaled", &generate(300_000, 2022));
+}
+
+criterion_group!(benches, day_six);
+criterion_main!(benches);
@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+use crate::detector::MarkerDetector;
+
+// Every marker of a given size in `data`, as byte ranges. Markers never
+// overlap: the search starts over right after each one.
+pub struct Markers<'a> {
+    data: &'a [u8],
+    detector: MarkerDetector,
+}
+
+impl<'a> Markers<'a> {
+    pub fn new(data: &'a [u8], n: usize) -> Self {
+        Self {
+            data,
+            detector: MarkerDetector::new(n),
+        }
+    }
+}
+
+impl Iterator for Markers<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(byte) = self.data.get(self.detector.position()) {
+            if let Some(end) = self.detector.push(*byte) {
+                self.detector.restart();
+
+                return Some(end - self.detector.size()..end);
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Segment<'a> {
+    pub marker: Range<usize>,
+    pub payload: Range<usize>,
+    pub data: &'a [u8],
+}
+
+// Splits `data` into segments that each start with a marker of size `n` and
+// run up to the next marker, or the end of the stream for the last one.
+// Anything before the first marker is not part of a segment.
+pub fn decode(data: &[u8], n: usize) -> Vec<Segment<'_>> {
+    let markers = Markers::new(data, n).collect::<Vec<Range<usize>>>();
+
+    markers
+        .iter()
+        .enumerate()
+        .map(|(i, marker)| {
+            let end = markers.get(i + 1).map_or(data.len(), |next| next.start);
+
+            Segment {
+                marker: marker.clone(),
+                payload: marker.end..end,
+                data: &data[marker.end..end],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_every_marker_without_overlaps() {
+        let markers = Markers::new(b"abcdabcdaaabcd", 4).collect::<Vec<Range<usize>>>();
+
+        assert_eq!(markers, vec![0..4, 4..8, 10..14]);
+    }
+
+    #[test]
+    fn should_split_the_stream_into_messages() {
+        let data = b"aaabccccxyyyy";
+
+        let segments = decode(data, 3);
+
+        assert_eq!(
+            segments,
+            vec![
+                Segment {
+                    marker: 2..5,
+                    payload: 5..7,
+                    data: b"cc",
+                },
+                Segment {
+                    marker: 7..10,
+                    payload: 10..13,
+                    data: b"yyy",
+                },
+            ]
+        );
+    }
+}
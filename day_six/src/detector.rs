@@ -0,0 +1,191 @@
+use std::{
+    collections::HashMap,
+    io::{self, ErrorKind, Read},
+    mem,
+};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+// Incremental form of the sliding window search: bytes are pushed one at a
+// time, so the input never has to be held in memory.
+#[derive(Clone, Debug)]
+pub struct MarkerDetector {
+    n: usize,
+    // One-based positions so that zero means "not seen yet"
+    last_seen: [usize; 256],
+    // Same, for chars that don't fit in a byte
+    wide: HashMap<char, usize>,
+    // Same, for grapheme clusters of more than one char
+    clusters: HashMap<String, usize>,
+    start: usize,
+    position: usize,
+}
+
+impl MarkerDetector {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            last_seen: [0; 256],
+            wide: HashMap::new(),
+            clusters: HashMap::new(),
+            start: 0,
+            position: 0,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    // Number of bytes pushed so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    // Start looking for a fresh window from the current position, so that the
+    // next marker cannot overlap the one just found
+    pub fn restart(&mut self) {
+        self.start = self.position;
+    }
+
+    // Returns the end index of the marker if `byte` completes one
+    pub fn push(&mut self, byte: u8) -> Option<usize> {
+        let seen = mem::replace(&mut self.last_seen[byte as usize], self.position + 1);
+
+        self.advance(seen)
+    }
+
+    // Like `push`, but treating each char as one symbol. Don't mix the two on
+    // the same detector.
+    pub fn push_char(&mut self, c: char) -> Option<usize> {
+        let seen = match u8::try_from(c) {
+            Ok(byte) => mem::replace(&mut self.last_seen[byte as usize], self.position + 1),
+            Err(_) => self.wide.insert(c, self.position + 1).unwrap_or(0),
+        };
+
+        self.advance(seen)
+    }
+
+    // Like `push_char`, but treating each grapheme cluster as one symbol, such
+    // as a letter with its accents or an emoji with its modifiers
+    pub fn push_grapheme(&mut self, grapheme: &str) -> Option<usize> {
+        let mut chars = grapheme.chars();
+
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return self.push_char(c);
+        }
+
+        let seen = self
+            .clusters
+            .insert(grapheme.to_string(), self.position + 1)
+            .unwrap_or(0);
+
+        self.advance(seen)
+    }
+
+    fn advance(&mut self, seen: usize) -> Option<usize> {
+        self.position += 1;
+        self.start = self.start.max(seen);
+
+        (self.position - self.start == self.n).then_some(self.position)
+    }
+
+    pub fn feed(&mut self, chunk: &[u8]) -> Option<usize> {
+        chunk.iter().find_map(|byte| self.push(*byte))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Marker {
+    pub size: usize,
+    pub end: usize,
+}
+
+// Reads `reader` in fixed size chunks, calling `report` as soon as the first
+// marker of each size is found. Stops reading once every marker has turned up.
+pub fn scan_stream<R: Read>(
+    mut reader: R,
+    sizes: &[usize],
+    mut report: impl FnMut(Marker),
+) -> io::Result<()> {
+    let mut detectors = sizes
+        .iter()
+        .map(|n| MarkerDetector::new(*n))
+        .collect::<Vec<MarkerDetector>>();
+
+    let mut buffer = [0u8; CHUNK_SIZE];
+
+    while !detectors.is_empty() {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+
+        let chunk = &buffer[..read];
+
+        // Each detector only looks at the chunk up to where it finds its
+        // marker, and is then dropped
+        detectors.retain_mut(|detector| match detector.feed(chunk) {
+            Some(end) => {
+                report(Marker {
+                    size: detector.size(),
+                    end,
+                });
+                false
+            }
+            None => true,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hands out at most `step` bytes per read to mimic a slow pipe
+    struct Trickle<'a> {
+        data: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.step.min(buf.len()).min(self.data.len());
+
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn should_find_markers_across_chunk_boundaries() {
+        let reader = Trickle {
+            data: b"mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            step: 3,
+        };
+
+        let mut markers = vec![];
+
+        scan_stream(reader, &[4, 14], |marker| markers.push(marker)).unwrap();
+
+        assert_eq!(
+            markers,
+            vec![Marker { size: 4, end: 7 }, Marker { size: 14, end: 19 }]
+        );
+    }
+
+    #[test]
+    fn should_report_nothing_for_a_stream_without_markers() {
+        let mut markers = vec![];
+
+        scan_stream(&b"aabbaabb"[..], &[4], |marker| markers.push(marker)).unwrap();
+
+        assert!(markers.is_empty());
+    }
+}
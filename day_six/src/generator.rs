@@ -0,0 +1,25 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// A datastream of `length` lowercase letters. Most of it only uses a-h, so
+// packet markers are common but message markers can't occur, except for one
+// run of fourteen distinct letters somewhere in the second half.
+pub fn generate(length: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let mut stream = (0..length)
+        .map(|_| rng.gen_range(b'a'..=b'h'))
+        .collect::<Vec<u8>>();
+
+    if length >= 14 {
+        let mut message = (b'a'..=b'z').collect::<Vec<u8>>();
+
+        message.shuffle(&mut rng);
+
+        let at = rng.gen_range((length - 14) / 2..=length - 14);
+
+        stream[at..at + 14].copy_from_slice(&message[..14]);
+    }
+
+    String::from_utf8(stream).unwrap()
+}
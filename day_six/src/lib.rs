@@ -0,0 +1,30 @@
+pub mod decoder;
+pub mod detector;
+#[cfg(test)]
+mod generator;
+pub mod search;
+
+use std::error::Error;
+
+use common::normalize::normalize;
+use common::Solution;
+
+use search::{end_index_of_first_marker, SearchOptions};
+
+fn first_marker(input: &str, n: usize) -> Result<String, Box<dyn Error>> {
+    end_index_of_first_marker(&normalize(input), n, SearchOptions::default())
+        .map(|end| end.to_string())
+        .ok_or_else(|| format!("no marker of {} distinct characters", n).into())
+}
+
+pub struct DaySix;
+
+impl Solution for DaySix {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        first_marker(input, 4)
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        first_marker(input, 14)
+    }
+}
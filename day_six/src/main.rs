@@ -1,23 +1,109 @@
-use std::{collections::HashSet, fs};
-
-pub(crate) fn end_index_of_first_marker(s: &str, n: usize) -> Option<usize> {
-    s.chars()
-        .collect::<Vec<char>>()
-        .windows(n)
-        .enumerate()
-        .find(|(_, chars)| HashSet::<_>::from_iter(chars.into_iter()).len() == n)
-        .map(|(i, _)| i + n)
+use std::{
+    env,
+    fs::File,
+    io::{self, Read},
+};
+
+use common::{inputs::open_input, local_input};
+
+use day_six::decoder::{decode, Markers};
+use day_six::detector::{scan_stream, Marker};
+use day_six::search::{end_index_of_first_marker, Mode, SearchOptions};
+
+const PACKET_MARKER: usize = 4;
+const MESSAGE_MARKER: usize = 14;
+
+// The resolved input by default, or the given path, or stdin for `-`. All of
+// them are read as they are, so byte offsets are offsets into the file.
+fn open(source: Option<String>) -> Box<dyn Read> {
+    let Some(source) = source else {
+        let input = open_input(local_input!(), 2022, 6)
+            .unwrap_or_else(|err| panic!("Unable to read input: {}", err));
+
+        return Box::new(input);
+    };
+
+    match source.as_str() {
+        "-" => Box::new(io::stdin().lock()),
+        path => Box::new(File::open(path).expect("Unable to read input")),
+    }
+}
+
+fn print_decoded(mut reader: impl Read) {
+    let mut data = vec![];
+
+    reader.read_to_end(&mut data).expect("Unable to read input");
+
+    println!(
+        "packet markers: {}",
+        Markers::new(&data, PACKET_MARKER).count()
+    );
+
+    for (i, segment) in decode(&data, MESSAGE_MARKER).iter().enumerate() {
+        println!(
+            "message {}: marker {:?}, payload {:?}: {}",
+            i,
+            segment.marker,
+            segment.payload,
+            String::from_utf8_lossy(segment.data)
+        );
+    }
+}
+
+fn print_first_markers(mut reader: impl Read, options: SearchOptions) {
+    let mut input = String::new();
+
+    reader
+        .read_to_string(&mut input)
+        .expect("Unable to read input");
+
+    for (name, n) in [("packet", PACKET_MARKER), ("message", MESSAGE_MARKER)] {
+        match end_index_of_first_marker(&input, n, options) {
+            Some(end) => println!("first {} marker: {}", name, end),
+            None => println!("no {} marker", name),
+        }
+    }
 }
 
 fn main() {
-    let input = fs::read_to_string("input.txt").expect("Unable to read input");
-    let input = input.as_str();
+    let mut args = env::args().skip(1).peekable();
+
+    // `decode [source]` splits the whole stream into messages
+    if args.next_if_eq("decode").is_some() {
+        print_decoded(open(args.next()));
+        return;
+    }
+
+    // Any search option switches from streaming raw bytes to searching the
+    // whole input as text
+    let mut options: Option<SearchOptions> = None;
 
-    let index_of_first_packet_marker = end_index_of_first_marker(input, 4).unwrap();
-    let index_of_first_message_marker = end_index_of_first_marker(input, 14).unwrap();
+    while let Some(flag) = args.next_if(|arg| arg.starts_with("--")) {
+        let options = options.get_or_insert_with(SearchOptions::default);
 
-    println!("first packet marker: {}", index_of_first_packet_marker);
-    println!("first message marker: {}", index_of_first_message_marker);
+        match flag.as_str() {
+            "--chars" => options.mode = Mode::Chars,
+            "--chars-at-byte-offsets" => options.mode = Mode::CharsAtByteOffsets,
+            "--graphemes" => options.mode = Mode::Graphemes,
+            "--skip-whitespace" => options.skip_whitespace = true,
+            flag => panic!("Unknown option: {}", flag),
+        }
+    }
+
+    if let Some(options) = options {
+        print_first_markers(open(args.next()), options);
+        return;
+    }
+
+    scan_stream(
+        open(args.next()),
+        &[PACKET_MARKER, MESSAGE_MARKER],
+        |Marker { size, end }| match size {
+            PACKET_MARKER => println!("first packet marker: {}", end),
+            _ => println!("first message marker: {}", end),
+        },
+    )
+    .expect("Unable to read input");
 }
 
 #[cfg(test)]
@@ -25,11 +111,64 @@ mod tests {
 
     use super::*;
 
+    use common::Solution;
+    use day_six::DaySix;
+
     #[test]
     fn should_find_the_first_marker_index() {
         assert_eq!(
-            end_index_of_first_marker("bvwbjplbgvbhsrlpgdmjqwftvncz, n: usize", 4).unwrap(),
+            end_index_of_first_marker(
+                "bvwbjplbgvbhsrlpgdmjqwftvncz, n: usize",
+                4,
+                SearchOptions::default()
+            )
+            .unwrap(),
             5
         );
     }
+
+    #[test]
+    fn should_find_markers_in_the_samples() {
+        let samples: [(&str, usize, usize); 5] = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (sample, packet, message) in samples {
+            assert_eq!(
+                end_index_of_first_marker(sample, 4, SearchOptions::default()),
+                Some(packet)
+            );
+            assert_eq!(
+                end_index_of_first_marker(sample, 14, SearchOptions::default()),
+                Some(message)
+            );
+        }
+    }
+
+    #[test]
+    fn should_ignore_boms_and_trailing_newlines() {
+        assert_eq!(
+            DaySix
+                .part_one("\u{feff}mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n")
+                .unwrap(),
+            "7"
+        );
+        assert!(DaySix.part_one("abc\r\n").is_err());
+    }
+
+    #[test]
+    fn should_not_find_a_marker_without_enough_distinct_bytes() {
+        assert_eq!(
+            end_index_of_first_marker("aaaaabbbbb", 3, SearchOptions::default()),
+            None
+        );
+        assert_eq!(
+            end_index_of_first_marker("abc", 4, SearchOptions::default()),
+            None
+        );
+    }
 }
@@ -0,0 +1,192 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::detector::MarkerDetector;
+
+// What counts as one symbol of the marker, and how the returned index is measured
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    // Every byte is a symbol, indices are byte offsets
+    #[default]
+    Bytes,
+    // Every char is a symbol, indices count chars
+    Chars,
+    // Every char is a symbol, indices are byte offsets into the UTF-8 input
+    CharsAtByteOffsets,
+    // Every extended grapheme cluster is a symbol, so "é" is one whether or
+    // not it is written with a combining accent. Indices count clusters.
+    Graphemes,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SearchOptions {
+    pub mode: Mode,
+    // Leave whitespace and control characters out of the marker entirely,
+    // e.g. a trailing newline. Indices still count them.
+    pub skip_whitespace: bool,
+}
+
+fn is_skipped(c: char, options: SearchOptions) -> bool {
+    options.skip_whitespace && (c.is_whitespace() || c.is_control())
+}
+
+// Bytes of multi-byte chars are never skipped, only ASCII ones
+fn is_skipped_byte(byte: u8, options: SearchOptions) -> bool {
+    options.skip_whitespace && (byte.is_ascii_whitespace() || byte.is_ascii_control())
+}
+
+pub fn end_index_of_first_marker(s: &str, n: usize, options: SearchOptions) -> Option<usize> {
+    let mut detector = MarkerDetector::new(n);
+
+    match options.mode {
+        Mode::Bytes => s
+            .bytes()
+            .enumerate()
+            .filter(|(_, byte)| !is_skipped_byte(*byte, options))
+            .find_map(|(i, byte)| detector.push(byte).map(|_| i + 1)),
+        Mode::Chars => s
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| !is_skipped(*c, options))
+            .find_map(|(i, c)| detector.push_char(c).map(|_| i + 1)),
+        Mode::CharsAtByteOffsets => s
+            .char_indices()
+            .filter(|(_, c)| !is_skipped(*c, options))
+            .find_map(|(i, c)| detector.push_char(c).map(|_| i + c.len_utf8())),
+        // A cluster such as "\r\n" is only skipped if all of it would be
+        Mode::Graphemes => s
+            .graphemes(true)
+            .enumerate()
+            .filter(|(_, g)| !g.chars().all(|c| is_skipped(c, options)))
+            .find_map(|(i, g)| detector.push_grapheme(g).map(|_| i + 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::generate;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn options(mode: Mode, skip_whitespace: bool) -> SearchOptions {
+        SearchOptions {
+            mode,
+            skip_whitespace,
+        }
+    }
+
+    #[test]
+    fn char_and_byte_offsets_differ_for_non_ascii_input() {
+        // 'é' and 'ü' take two bytes each
+        let input = "ééabüc";
+
+        assert_eq!(
+            end_index_of_first_marker(input, 4, options(Mode::Chars, false)),
+            Some(5)
+        );
+        assert_eq!(
+            end_index_of_first_marker(input, 4, options(Mode::CharsAtByteOffsets, false)),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn grapheme_mode_keeps_clusters_whole() {
+        // Each thumb is a thumb followed by a skin tone modifier
+        let input = "👍🏽👍🏾ab";
+
+        assert_eq!(
+            end_index_of_first_marker(input, 4, options(Mode::Graphemes, false)),
+            Some(4)
+        );
+        assert_eq!(
+            end_index_of_first_marker(input, 4, options(Mode::Chars, false)),
+            Some(5)
+        );
+
+        // "e" with a combining acute accent, then with a combining grave one
+        let accents = "e\u{301}e\u{300}ee";
+
+        assert_eq!(
+            end_index_of_first_marker(accents, 3, options(Mode::Graphemes, false)),
+            Some(3)
+        );
+        assert_eq!(
+            end_index_of_first_marker(accents, 3, options(Mode::Chars, false)),
+            Some(4)
+        );
+
+        // "\r\n" is a single cluster, skipped as a whole
+        assert_eq!(
+            end_index_of_first_marker("ab\r\ncd", 4, options(Mode::Graphemes, true)),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn byte_mode_treats_multibyte_chars_as_several_symbols() {
+        // 'é' is 0xC3 0xA9, which already makes two distinct bytes
+        assert_eq!(
+            end_index_of_first_marker("aéb", 4, options(Mode::Bytes, false)),
+            Some(4)
+        );
+        assert_eq!(
+            end_index_of_first_marker("aéb", 4, options(Mode::Chars, false)),
+            None
+        );
+    }
+
+    #[test]
+    fn skipping_whitespace_ignores_a_trailing_newline() {
+        let input = "aaabc\n";
+
+        assert_eq!(
+            end_index_of_first_marker(input, 4, options(Mode::Bytes, false)),
+            Some(6)
+        );
+        assert_eq!(
+            end_index_of_first_marker(input, 4, options(Mode::Bytes, true)),
+            None
+        );
+
+        // Skipped characters still count towards the index
+        assert_eq!(
+            end_index_of_first_marker("ab\r\ncd", 4, options(Mode::Chars, true)),
+            Some(6)
+        );
+    }
+
+    fn is_distinct(window: &[u8]) -> bool {
+        window.iter().collect::<HashSet<&u8>>().len() == window.len()
+    }
+
+    proptest! {
+        #[test]
+        fn marker_is_the_first_distinct_window(stream in "[a-p]{0,200}", n in 1usize..15) {
+            let bytes = stream.as_bytes();
+
+            let first = bytes.windows(n).position(is_distinct).map(|start| start + n);
+
+            prop_assert_eq!(
+                end_index_of_first_marker(&stream, n, SearchOptions::default()),
+                first
+            );
+
+            if let Some(end) = first {
+                prop_assert!(is_distinct(&bytes[end - n..end]));
+            }
+        }
+    }
+
+    #[test]
+    fn streams_carry_both_kinds_of_marker() {
+        let stream = generate(10_000, 4);
+
+        assert_eq!(stream.len(), 10_000);
+
+        let options = SearchOptions::default();
+
+        assert!(end_index_of_first_marker(&stream, 4, options).is_some());
+        assert!(end_index_of_first_marker(&stream, 14, options).unwrap() >= 5000);
+    }
+}
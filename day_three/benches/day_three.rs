@@ -0,0 +1,47 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_three::priority::PriorityTable;
+use day_three::{part_one, part_two};
+use generator::generate;
+
+const SAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let table = PriorityTable::default();
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| {
+        b.iter(|| {
+            black_box(input)
+                .lines()
+                .enumerate()
+                .map(|(i, rucksack)| table.compartments(i + 1, rucksack, 2))
+                .collect::<Result<Vec<_>, _>>()
+        })
+    });
+    group.bench_function("part one", |b| {
+        b.iter(|| part_one(&table, black_box(input)))
+    });
+    group.bench_function("part two", |b| {
+        b.iter(|| part_two(&table, black_box(input), 3))
+    });
+
+    group.finish();
+}
+
+fn day_three(c: &mut Criterion) {
+    bench(c, "day_three/sample", SAMPLE);
+    bench(c, "day_three/scaled", &generate(1000, 2022));
+}
+
+criterion_group!(benches, day_three);
+criterion_main!(benches);
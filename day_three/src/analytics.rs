@@ -0,0 +1,270 @@
+use std::{error::Error, fmt};
+
+use crate::priority::{shared_by, PriorityTable, RucksackError};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AnalyticsError {
+    // Items can only be counted in 1 to k of k compartments
+    InvalidThreshold {
+        at_least: usize,
+        compartments: usize,
+    },
+    Rucksack(RucksackError),
+}
+
+impl From<RucksackError> for AnalyticsError {
+    fn from(err: RucksackError) -> Self {
+        AnalyticsError::Rucksack(err)
+    }
+}
+
+impl fmt::Display for AnalyticsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnalyticsError::InvalidThreshold {
+                at_least,
+                compartments,
+            } => write!(
+                f,
+                "unable to count items in at least {} of {} compartments",
+                at_least, compartments
+            ),
+            AnalyticsError::Rucksack(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for AnalyticsError {}
+
+// The item types of one rucksack found in every compartment, and in at least
+// `m` of them
+#[derive(Debug, PartialEq, Eq)]
+pub struct SharedItems {
+    pub line: usize,
+    pub in_all: u64,
+    pub in_at_least: u64,
+}
+
+// How often each item type turned up in more than one compartment, across
+// the whole input
+#[derive(Debug, PartialEq, Eq)]
+pub struct Analytics {
+    // Indexed by priority
+    misplaced: [usize; 64],
+    pub rucksacks: usize,
+    pub compartments: usize,
+    pub at_least: usize,
+    // In input order
+    pub shared: Vec<SharedItems>,
+}
+
+impl Analytics {
+    pub fn count(&self, priority: u8) -> usize {
+        self.misplaced[priority as usize]
+    }
+
+    // Priority times the number of rucksacks it was misplaced in
+    pub fn priority_total(&self, priority: u8) -> usize {
+        priority as usize * self.count(priority)
+    }
+
+    pub fn total(&self) -> usize {
+        (1..64).map(|p| self.priority_total(p)).sum()
+    }
+
+    // Ties go to the lowest priority
+    pub fn most_misplaced(&self) -> Option<u8> {
+        (1..64u8)
+            .filter(|p| self.count(*p) > 0)
+            .max_by_key(|p| (self.count(*p), std::cmp::Reverse(*p)))
+    }
+}
+
+// Splits every rucksack into `k` compartments and counts the item types found
+// in at least `m` of them
+pub fn analyse(
+    table: &PriorityTable,
+    rucksacks: &str,
+    k: usize,
+    m: usize,
+) -> Result<Analytics, Vec<AnalyticsError>> {
+    if m == 0 || m > k {
+        return Err(vec![AnalyticsError::InvalidThreshold {
+            at_least: m,
+            compartments: k,
+        }]);
+    }
+
+    let mut analytics = Analytics {
+        misplaced: [0; 64],
+        rucksacks: 0,
+        compartments: k,
+        at_least: m,
+        shared: vec![],
+    };
+    let mut errors: Vec<AnalyticsError> = vec![];
+
+    for (i, rucksack) in rucksacks.lines().enumerate() {
+        match table.compartments(i + 1, rucksack, k) {
+            Ok(masks) => {
+                let shared = shared_by(&masks, m);
+
+                for p in (1..64).filter(|p| shared & 1 << p != 0) {
+                    analytics.misplaced[p] += 1;
+                }
+
+                analytics.rucksacks += 1;
+                analytics.shared.push(SharedItems {
+                    line: i + 1,
+                    in_all: shared_by(&masks, k),
+                    in_at_least: shared,
+                });
+            }
+            Err(err) => errors.push(err.into()),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(analytics)
+    } else {
+        Err(errors)
+    }
+}
+
+// The item types in a mask, or `-` for none
+fn list_items(table: &PriorityTable, mask: u64) -> String {
+    match table.items(mask) {
+        items if items.is_empty() => "-".to_string(),
+        items => items,
+    }
+}
+
+pub fn format_analytics(table: &PriorityTable, analytics: &Analytics) -> String {
+    let mut lines = vec![format!("rucksacks: {}", analytics.rucksacks)];
+
+    match analytics.most_misplaced() {
+        Some(p) => lines.push(format!(
+            "most misplaced: {} ({} rucksacks)",
+            table.item(p).unwrap_or('?'),
+            analytics.count(p)
+        )),
+        None => lines.push("most misplaced: none".to_string()),
+    }
+
+    for p in (1..=table.len() as u8).filter(|p| analytics.count(*p) > 0) {
+        lines.push(format!(
+            "{}  priority {:>2}  misplaced {:>4}  total {:>6}",
+            table.item(p).unwrap_or('?'),
+            p,
+            analytics.count(p),
+            analytics.priority_total(p)
+        ));
+    }
+
+    lines.push(format!("total: {}", analytics.total()));
+
+    for shared in &analytics.shared {
+        lines.push(format!(
+            "line {:>5}  in all {}: {}  in at least {}: {}",
+            shared.line,
+            analytics.compartments,
+            list_items(table, shared.in_all),
+            analytics.at_least,
+            list_items(table, shared.in_at_least)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn two_compartments_match_part_one() {
+        let table = PriorityTable::default();
+
+        let analytics = analyse(&table, SAMPLE, 2, 2).unwrap();
+
+        assert_eq!(analytics.rucksacks, 6);
+        assert_eq!(analytics.total(), 157);
+        // p, L, P, v, t and s are each misplaced once, and p sorts first
+        assert_eq!(analytics.most_misplaced(), Some(16));
+    }
+
+    #[test]
+    fn counts_items_shared_by_some_compartments() {
+        let table = PriorityTable::default();
+
+        let analytics = analyse(&table, "aabc\nabac\nbbcd", 4, 2).unwrap();
+
+        assert_eq!(analytics.count(1), 2);
+        assert_eq!(analytics.count(2), 1);
+        assert_eq!(analytics.most_misplaced(), Some(1));
+        assert_eq!(analytics.total(), 4);
+    }
+
+    #[test]
+    fn lists_the_shared_items_of_each_rucksack() {
+        let table = PriorityTable::default();
+
+        let analytics = analyse(&table, "abacadbd\nabcd", 4, 2).unwrap();
+
+        assert_eq!(
+            analytics.shared,
+            vec![
+                SharedItems {
+                    line: 1,
+                    in_all: 0,
+                    in_at_least: table.mask(1, "abd").unwrap()
+                },
+                SharedItems {
+                    line: 2,
+                    in_all: 0,
+                    in_at_least: 0
+                },
+            ]
+        );
+        assert_eq!(
+            format_analytics(&table, &analytics)
+                .lines()
+                .skip_while(|line| !line.starts_with("line"))
+                .collect::<Vec<&str>>(),
+            vec![
+                "line     1  in all 4: -  in at least 2: abd",
+                "line     2  in all 4: -  in at least 2: -",
+            ]
+        );
+
+        let analytics = analyse(&table, "vJrwpWtwJgWrhcsFMMfFFhFp", 2, 1).unwrap();
+
+        assert_eq!(table.items(analytics.shared[0].in_all), "p");
+        assert_eq!(
+            table.items(analytics.shared[0].in_at_least),
+            "cfghprstvwFJMW"
+        );
+    }
+
+    #[test]
+    fn rejects_thresholds_outside_the_compartments() {
+        let table = PriorityTable::default();
+
+        for (k, m) in [(2, 0), (2, 3), (0, 0)] {
+            assert_eq!(
+                analyse(&table, "abab", k, m),
+                Err(vec![AnalyticsError::InvalidThreshold {
+                    at_least: m,
+                    compartments: k
+                }])
+            );
+        }
+    }
+}
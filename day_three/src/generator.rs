@@ -0,0 +1,56 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// A compartment of `length` items holding every one of `required` and
+// otherwise only items from `pool`, in random order
+fn compartment(rng: &mut ChaCha8Rng, length: usize, required: &[u8], pool: &[u8]) -> Vec<u8> {
+    let mut items = required.to_vec();
+
+    while items.len() < length {
+        items.push(*pool.choose(rng).unwrap());
+    }
+
+    items.shuffle(rng);
+
+    items
+}
+
+// The three rucksacks of a group. Apart from the badge, each rucksack draws
+// from its own third of the alphabet, so the badge is the only item all
+// three share. Within a rucksack, the left and right compartments split that
+// third between them, so the misplaced item is the only one in both.
+fn group(rng: &mut ChaCha8Rng) -> Vec<String> {
+    let mut items = ITEMS.to_vec();
+
+    items.shuffle(rng);
+
+    let (badge, rest) = items.split_first().unwrap();
+
+    rest.chunks(rest.len() / 3)
+        .map(|pool| {
+            let (misplaced, pool) = pool.split_first().unwrap();
+            let (left, right) = pool.split_at(pool.len() / 2);
+
+            let length = rng.gen_range(2..=16);
+
+            let mut rucksack = compartment(rng, length, &[*misplaced, *badge], left);
+
+            rucksack.extend(compartment(rng, length, &[*misplaced], right));
+
+            String::from_utf8(rucksack).unwrap()
+        })
+        .collect()
+}
+
+// `groups` groups of three rucksacks, each rucksack with exactly one item
+// type in both compartments and each group with exactly one badge
+pub fn generate(groups: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    (0..groups)
+        .flat_map(|_| group(&mut rng))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
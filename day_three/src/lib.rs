@@ -0,0 +1,387 @@
+pub mod analytics;
+#[cfg(test)]
+mod generator;
+pub mod priority;
+
+use std::{error::Error, fmt};
+
+use common::normalize::normalize_line_endings;
+use common::Solution;
+
+use priority::{mask_priority, PriorityTable, RucksackError};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GroupError {
+    // Groups need at least one rucksack
+    InvalidSize(usize),
+    // The input ran out part way through a group
+    Incomplete {
+        line: usize,
+        found: usize,
+        expected: usize,
+    },
+    NoBadge {
+        line: usize,
+    },
+    ManyBadges {
+        line: usize,
+        badges: String,
+    },
+    Rucksack(RucksackError),
+}
+
+impl From<RucksackError> for GroupError {
+    fn from(err: RucksackError) -> Self {
+        GroupError::Rucksack(err)
+    }
+}
+
+impl fmt::Display for GroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupError::InvalidSize(n) => {
+                write!(f, "groups of {} rucksacks can't be formed", n)
+            }
+            GroupError::Incomplete {
+                line,
+                found,
+                expected,
+            } => write!(
+                f,
+                "group starting on line {} has {} of {} rucksacks",
+                line, found, expected
+            ),
+            GroupError::NoBadge { line } => {
+                write!(f, "group starting on line {} has no badge", line)
+            }
+            GroupError::ManyBadges { line, badges } => write!(
+                f,
+                "group starting on line {} has several badges: {}",
+                line, badges
+            ),
+            GroupError::Rucksack(err) => err.fmt(f),
+        }
+    }
+}
+
+pub fn chunk_lines(string: &str, n: usize) -> Result<Vec<Vec<&str>>, GroupError> {
+    if n == 0 {
+        return Err(GroupError::InvalidSize(n));
+    }
+
+    let mut out: Vec<Vec<&str>> = vec![];
+
+    let mut temp: Vec<&str> = vec![];
+
+    for line in string.lines() {
+        temp.push(line);
+        if temp.len() == n {
+            out.push(temp);
+            temp = vec![];
+        }
+    }
+
+    if !temp.is_empty() {
+        return Err(GroupError::Incomplete {
+            line: out.len() * n + 1,
+            found: temp.len(),
+            expected: n,
+        });
+    }
+
+    Ok(out)
+}
+
+// The badge is the one item type every rucksack in the group carries
+pub fn find_badge(table: &PriorityTable, group: &[&str], line: usize) -> Result<u8, GroupError> {
+    let mut common = u64::MAX;
+
+    for (i, rucksack) in group.iter().enumerate() {
+        if rucksack.is_empty() {
+            return Err(RucksackError::Empty { line: line + i }.into());
+        }
+
+        common &= table.mask(line + i, rucksack)?;
+    }
+
+    match common.count_ones() {
+        0 => Err(GroupError::NoBadge { line }),
+        1 => Ok(mask_priority(common)),
+        _ => Err(GroupError::ManyBadges {
+            line,
+            badges: table.items(common),
+        }),
+    }
+}
+
+// Returns the priority of the item type found in both compartments
+pub fn find_mistake_in_rucksack(
+    table: &PriorityTable,
+    line: usize,
+    rucksack: &str,
+) -> Result<u8, RucksackError> {
+    let masks = table.compartments(line, rucksack, 2)?;
+    let shared = masks[0] & masks[1];
+
+    match shared.count_ones() {
+        0 => Err(RucksackError::NoMistake { line }),
+        1 => Ok(mask_priority(shared)),
+        _ => Err(RucksackError::ManyMistakes {
+            line,
+            items: table.items(shared),
+        }),
+    }
+}
+
+// Collects every malformed rucksack rather than stopping at the first
+pub fn part_one(table: &PriorityTable, rucksacks: &str) -> Result<i32, Vec<RucksackError>> {
+    let mut total: i32 = 0;
+    let mut errors: Vec<RucksackError> = vec![];
+
+    for (i, line) in rucksacks.lines().enumerate() {
+        match find_mistake_in_rucksack(table, i + 1, line) {
+            Ok(mistake) => total += mistake as i32,
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(total)
+    } else {
+        Err(errors)
+    }
+}
+
+// Collects every malformed group rather than stopping at the first
+pub fn part_two(table: &PriorityTable, rucksacks: &str, n: usize) -> Result<i32, Vec<GroupError>> {
+    let groups = chunk_lines(rucksacks, n).map_err(|err| vec![err])?;
+
+    let mut total: i32 = 0;
+    let mut errors: Vec<GroupError> = vec![];
+
+    for (i, group) in groups.iter().enumerate() {
+        match find_badge(table, group, i * n + 1) {
+            Ok(badge) => total += badge as i32,
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(total)
+    } else {
+        Err(errors)
+    }
+}
+
+// Every malformed rucksack or group, one per line
+fn join_errors<E: fmt::Display>(errors: Vec<E>) -> Box<dyn Error> {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join("\n")
+        .into()
+}
+
+pub struct DayThree;
+
+impl Solution for DayThree {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let total = part_one(&PriorityTable::default(), &normalize_line_endings(input))
+            .map_err(join_errors)?;
+
+        Ok(total.to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let total = part_two(&PriorityTable::default(), &normalize_line_endings(input), 3)
+            .map_err(join_errors)?;
+
+        Ok(total.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::generate;
+
+    #[test]
+    fn letters_to_number_should_return_value_in_range_1_to_52() {
+        let all_letters = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        let table = PriorityTable::default();
+
+        let out = all_letters
+            .chars()
+            .filter_map(|c| table.priority(c))
+            .collect::<Vec<u8>>();
+
+        let expected = (1..53).collect::<Vec<u8>>();
+
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn masks_share_only_common_items() {
+        let table = PriorityTable::default();
+
+        assert_eq!(
+            find_mistake_in_rucksack(&table, 1, "vJrwpWtwJgWrhcsFMMfFFhFp"),
+            Ok(16)
+        );
+        assert_eq!(
+            find_mistake_in_rucksack(&table, 2, "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"),
+            Ok(38)
+        );
+        assert_eq!(
+            find_mistake_in_rucksack(&table, 3, "PmmdzqPrVvPwwTWBwg"),
+            Ok(42)
+        );
+
+        let group = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ];
+
+        assert_eq!(find_badge(&table, &group, 1), Ok(18));
+        assert_eq!(mask_priority(0), 0);
+    }
+
+    #[test]
+    fn chunk_lines_into_threes() {
+        let string = "a\nb\nc\na\nb\nc\na\nb\nc";
+
+        let out = chunk_lines(string, 3).unwrap();
+
+        assert_eq!(
+            out,
+            vec!(
+                vec!("a", "b", "c"),
+                vec!("a", "b", "c"),
+                vec!("a", "b", "c")
+            )
+        )
+    }
+
+    #[test]
+    fn chunk_lines_uses_the_group_size() {
+        let string = "a\nb\nc\nd";
+
+        assert_eq!(
+            chunk_lines(string, 2).unwrap(),
+            vec!(vec!("a", "b"), vec!("c", "d"))
+        );
+        assert_eq!(
+            chunk_lines(string, 3),
+            Err(GroupError::Incomplete {
+                line: 4,
+                found: 1,
+                expected: 3
+            })
+        );
+        assert_eq!(chunk_lines(string, 0), Err(GroupError::InvalidSize(0)));
+        assert_eq!(
+            part_two(&PriorityTable::default(), string, 0),
+            Err(vec![GroupError::InvalidSize(0)])
+        );
+    }
+
+    #[test]
+    fn reports_groups_without_exactly_one_badge() {
+        let rucksacks = "abc\nbcd\nxyz\nXYZ\nabX\nXcd";
+
+        assert_eq!(
+            part_two(&PriorityTable::default(), rucksacks, 2),
+            Err(vec![
+                GroupError::ManyBadges {
+                    line: 1,
+                    badges: "bc".to_string()
+                },
+                GroupError::NoBadge { line: 3 },
+            ])
+        );
+        assert_eq!(
+            part_two(&PriorityTable::default(), "abc\nbcd\nbxy", 3),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn blank_lines_are_empty_rucksacks() {
+        assert_eq!(
+            DayThree
+                .part_one("vJrwpWtwJgWrhcsFMMfFFhFp\n\n")
+                .map_err(|err| err.to_string()),
+            Err("line 2: empty rucksack".to_string())
+        );
+        assert_eq!(
+            part_two(&PriorityTable::default(), "ab\n\nab", 3),
+            Err(vec![GroupError::Rucksack(RucksackError::Empty { line: 2 })])
+        );
+        assert_eq!(
+            DayThree
+                .part_one("\u{feff}vJrwpWtwJgWrhcsFMMfFFhFp\r\n")
+                .unwrap(),
+            "16"
+        );
+    }
+
+    #[test]
+    fn rucksacks_need_exactly_one_misplaced_item() {
+        let table = PriorityTable::default();
+
+        assert_eq!(
+            part_one(&table, "abcd\nabab\nabcb"),
+            Err(vec![
+                RucksackError::NoMistake { line: 1 },
+                RucksackError::ManyMistakes {
+                    line: 2,
+                    items: "ab".to_string()
+                },
+            ])
+        );
+        assert_eq!(
+            RucksackError::ManyMistakes {
+                line: 2,
+                items: "ab".to_string()
+            }
+            .to_string(),
+            "line 2: several item types are in both compartments: ab"
+        );
+    }
+
+    #[test]
+    fn malformed_rucksacks_are_reported_by_line() {
+        let table = PriorityTable::default();
+
+        assert_eq!(
+            part_one(&table, "abca\nabc\nab-b"),
+            Err(vec![
+                RucksackError::UnevenLength {
+                    line: 2,
+                    length: 3,
+                    compartments: 2
+                },
+                RucksackError::InvalidItem { line: 3, item: '-' },
+            ])
+        );
+    }
+
+    #[test]
+    fn rucksacks_have_one_misplaced_item_and_groups_one_badge() {
+        let table = PriorityTable::default();
+        let input = generate(100, 11);
+
+        assert_eq!(input.lines().count(), 300);
+
+        for (i, rucksack) in input.lines().enumerate() {
+            let masks = table.compartments(i + 1, rucksack, 2).unwrap();
+
+            assert_eq!((masks[0] & masks[1]).count_ones(), 1);
+        }
+
+        assert!(part_two(&table, &input, 3).is_ok());
+    }
+}
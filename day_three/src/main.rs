@@ -1,131 +1,82 @@
-use std::{collections::HashSet, fs, ops::Deref};
+use std::{env, fmt, fs, process};
 
-pub(crate) fn letter_to_number(c: &char) -> u8 {
-    let letter_as_byte: u8 = c.to_string().as_bytes().iter().next().unwrap().to_owned();
+use common::normalize::normalize;
+use common::{inputs::read_input, local_input};
 
-    let factor: u8 = if letter_as_byte >= 91 {
-        b'a'
-    } else {
-        b'A' - 26
-    };
+use day_three::analytics::{analyse, format_analytics};
+use day_three::priority::PriorityTable;
+use day_three::{part_one, part_two};
 
-    let num = letter_as_byte - factor;
+fn print_result<E: fmt::Display>(result: Result<i32, Vec<E>>) {
+    match result {
+        Ok(total) => println!("{total}"),
+        Err(errors) => {
+            for err in errors {
+                eprintln!("{err}");
+            }
 
-    num + 1
-}
-
-fn chunk_lines(string: &str, n: usize) -> Vec<Vec<&str>> {
-    let mut out: Vec<Vec<&str>> = vec![];
-
-    let mut temp: Vec<&str> = vec![];
-
-    for line in string.lines() {
-        temp.push(line);
-        if temp.len() == 3 {
-            out.push(temp);
-            temp = vec![];
+            process::exit(1);
         }
     }
-
-    out
-}
-
-fn find_mistake_in_rucksack(rucksack: &str) -> char {
-    let (left, right) = rucksack.split_at(rucksack.len() / 2);
-
-    let left_chars = left.chars().collect::<HashSet<char>>();
-    let right_chars = right.chars().collect::<HashSet<char>>();
-
-    let intersection: HashSet<&char> = left_chars.intersection(&right_chars).collect();
-
-    let c = intersection.into_iter().next().unwrap();
-
-    *c
 }
 
-fn part_one(rucksacks: &str) {
-    let mut total: i32 = 0;
-
-    for line in rucksacks.lines() {
-        let mistake = find_mistake_in_rucksack(line);
-
-        let mistake_as_number = letter_to_number(&mistake);
-
-        total += mistake_as_number as i32;
-    }
-
-    println!("{total}");
-}
-
-fn part_two(rucksacks: &str) {
-    let groups = chunk_lines(rucksacks, 3);
-
-    let mut total: i32 = 0;
-
-    let mut temp: HashSet<char>;
-
-    for group in groups {
-        let mut iter = group.iter();
-
-        temp = iter
-            .next()
-            .map(|g| g.chars().collect::<HashSet<char>>())
-            .unwrap();
-
-        while let Some(rucksack) = iter.next() {
-            let h = rucksack.chars().collect::<HashSet<char>>();
-
-            temp = h
-                .intersection(&temp)
-                .into_iter()
-                .map(|c| *c)
-                .collect::<HashSet<char>>();
-        }
-        let c = temp.into_iter().next().unwrap();
-
-        total += letter_to_number(&c) as i32;
-    }
-
-    println!("{total}");
+fn flag<'a>(args: &'a [String], name: &str) -> Option<&'a String> {
+    args.iter()
+        .position(|arg| arg == name)
+        .and_then(|i| args.get(i + 1))
 }
 
 fn main() {
-    let rucksacks = fs::read_to_string("input.txt").expect("unable to read input");
-
-    part_one(rucksacks.as_str());
-    part_two(rucksacks.as_str());
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn letters_to_number_should_return_value_in_range_1_to_52() {
-        let all_letters = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
-
-        let chars = all_letters.chars();
-
-        let out = chars.map(|c| letter_to_number(&c)).collect::<Vec<u8>>();
-
-        let expected = (1..53).collect::<Vec<u8>>();
+    let rucksacks = read_input(local_input!(), 2022, 3)
+        .unwrap_or_else(|err| panic!("Unable to read input: {}", err));
+
+    let args = env::args().collect::<Vec<String>>();
+
+    // `--group-size <n>` for elves travelling in groups of other than three
+    let group_size = flag(&args, "--group-size")
+        .map(|n| {
+            n.parse::<usize>()
+                .ok()
+                .filter(|n| *n >= 1)
+                .expect("Invalid group size")
+        })
+        .unwrap_or(3);
+
+    // `--alphabet <file>` lists the item types in priority order
+    let table = flag(&args, "--alphabet")
+        .map(|file| {
+            let alphabet = fs::read_to_string(file).expect("unable to read alphabet");
+
+            PriorityTable::from_alphabet(&normalize(&alphabet))
+                .unwrap_or_else(|err| panic!("Invalid alphabet: {}", err))
+        })
+        .unwrap_or_default();
+
+    // `analyse [--compartments <k>] [--at-least <m>]` counts the item types
+    // found in at least m of k compartments, all of them by default, and
+    // lists them for every rucksack along with those found in all k
+    if args.get(1).map(String::as_str) == Some("analyse") {
+        let k = flag(&args, "--compartments")
+            .map(|k| k.parse::<usize>().expect("Invalid number of compartments"))
+            .unwrap_or(2);
+        let m = flag(&args, "--at-least")
+            .map(|m| m.parse::<usize>().expect("Invalid number of compartments"))
+            .unwrap_or(k);
+
+        match analyse(&table, &rucksacks, k, m) {
+            Ok(analytics) => println!("{}", format_analytics(&table, &analytics)),
+            Err(errors) => {
+                for err in errors {
+                    eprintln!("{err}");
+                }
+
+                process::exit(1);
+            }
+        }
 
-        assert_eq!(out, expected);
+        return;
     }
 
-    #[test]
-    fn chunk_lines_into_threes() {
-        let string = "a\nb\nc\na\nb\nc\na\nb\nc";
-
-        let out = chunk_lines(string, 3);
-
-        assert_eq!(
-            out,
-            vec!(
-                vec!("a", "b", "c"),
-                vec!("a", "b", "c"),
-                vec!("a", "b", "c")
-            )
-        )
-    }
+    print_result(part_one(&table, rucksacks.as_str()));
+    print_result(part_two(&table, rucksacks.as_str(), group_size));
 }
@@ -0,0 +1,330 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+// Priorities are bit positions in a u64, and bit 0 means "no item"
+const MAX_ITEM_TYPES: usize = 63;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RucksackError {
+    // A blank line, which no rucksack can be
+    Empty {
+        line: usize,
+    },
+    // Every compartment must hold the same number of items
+    UnevenLength {
+        line: usize,
+        length: usize,
+        compartments: usize,
+    },
+    InvalidItem {
+        line: usize,
+        item: char,
+    },
+    // Exactly one item type should be in both compartments
+    NoMistake {
+        line: usize,
+    },
+    ManyMistakes {
+        line: usize,
+        items: String,
+    },
+}
+
+impl fmt::Display for RucksackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RucksackError::Empty { line } => write!(f, "line {}: empty rucksack", line),
+            RucksackError::UnevenLength {
+                line,
+                length,
+                compartments,
+            } => write!(
+                f,
+                "line {}: {} items can't be split into {} compartments",
+                line, length, compartments
+            ),
+            RucksackError::InvalidItem { line, item } => {
+                write!(f, "line {}: {:?} is not an item type", line, item)
+            }
+            RucksackError::NoMistake { line } => {
+                write!(f, "line {}: no item type is in both compartments", line)
+            }
+            RucksackError::ManyMistakes { line, items } => write!(
+                f,
+                "line {}: several item types are in both compartments: {}",
+                line, items
+            ),
+        }
+    }
+}
+
+impl Error for RucksackError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AlphabetError {
+    TooLarge(usize),
+    Duplicate(char),
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlphabetError::TooLarge(n) => write!(
+                f,
+                "{} item types given, at most {} are supported",
+                n, MAX_ITEM_TYPES
+            ),
+            AlphabetError::Duplicate(item) => write!(f, "{:?} is listed twice", item),
+        }
+    }
+}
+
+impl Error for AlphabetError {}
+
+// Maps item types to priorities, starting at 1 for the first item type in
+// the alphabet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityTable {
+    alphabet: Vec<char>,
+    priorities: HashMap<char, u8>,
+}
+
+impl Default for PriorityTable {
+    // a-z are 1 to 26 and A-Z are 27 to 52
+    fn default() -> Self {
+        Self::from_alphabet("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap()
+    }
+}
+
+impl PriorityTable {
+    // Whitespace in the alphabet is ignored, so it can be spread over lines
+    pub fn from_alphabet(alphabet: &str) -> Result<Self, AlphabetError> {
+        let alphabet = alphabet
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<Vec<char>>();
+
+        if alphabet.len() > MAX_ITEM_TYPES {
+            return Err(AlphabetError::TooLarge(alphabet.len()));
+        }
+
+        let mut priorities = HashMap::new();
+
+        for (i, item) in alphabet.iter().enumerate() {
+            if priorities.insert(*item, i as u8 + 1).is_some() {
+                return Err(AlphabetError::Duplicate(*item));
+            }
+        }
+
+        Ok(Self {
+            alphabet,
+            priorities,
+        })
+    }
+
+    pub fn priority(&self, item: char) -> Option<u8> {
+        self.priorities.get(&item).copied()
+    }
+
+    pub fn item(&self, priority: u8) -> Option<char> {
+        self.alphabet
+            .get((priority as usize).checked_sub(1)?)
+            .copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.alphabet.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.alphabet.is_empty()
+    }
+
+    // Each item type sets the bit at its priority, so a rucksack fits in a
+    // u64 and the items two rucksacks share are just `a & b`
+    pub fn mask(&self, line: usize, items: &str) -> Result<u64, RucksackError> {
+        self.mask_chars(line, items.chars())
+    }
+
+    fn mask_chars(
+        &self,
+        line: usize,
+        mut items: impl Iterator<Item = char>,
+    ) -> Result<u64, RucksackError> {
+        items.try_fold(0, |mask, item| {
+            let priority = self
+                .priority(item)
+                .ok_or(RucksackError::InvalidItem { line, item })?;
+
+            Ok(mask | 1 << priority)
+        })
+    }
+
+    // Masks for each of `k` equal compartments of a rucksack, front first
+    pub fn compartments(
+        &self,
+        line: usize,
+        rucksack: &str,
+        k: usize,
+    ) -> Result<Vec<u64>, RucksackError> {
+        // Split on char boundaries, not part way through a multi-byte item
+        let items = rucksack.chars().collect::<Vec<char>>();
+
+        if items.is_empty() {
+            return Err(RucksackError::Empty { line });
+        }
+
+        if k == 0 || !items.len().is_multiple_of(k) {
+            return Err(RucksackError::UnevenLength {
+                line,
+                length: items.len(),
+                compartments: k,
+            });
+        }
+
+        items
+            .chunks(items.len() / k)
+            .map(|compartment| self.mask_chars(line, compartment.iter().copied()))
+            .collect()
+    }
+
+    // The item types in a mask, lowest priority first
+    pub fn items(&self, mask: u64) -> String {
+        (1..=self.len() as u8)
+            .filter(|p| mask & 1 << p != 0)
+            .filter_map(|p| self.item(p))
+            .collect()
+    }
+}
+
+// Item types found in at least `m` of the masks
+pub fn shared_by(masks: &[u64], m: usize) -> u64 {
+    (1..64)
+        .map(|p| 1u64 << p)
+        .filter(|bit| masks.iter().filter(|mask| *mask & bit != 0).count() >= m)
+        .fold(0, |shared, bit| shared | bit)
+}
+
+// Priority of the lowest item type in the mask, or 0 if it is empty
+pub fn mask_priority(mask: u64) -> u8 {
+    if mask == 0 {
+        0
+    } else {
+        mask.trailing_zeros() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn rejects_odd_lengths_and_unknown_items() {
+        let table = PriorityTable::default();
+
+        assert_eq!(
+            table.compartments(3, "abc", 2),
+            Err(RucksackError::UnevenLength {
+                line: 3,
+                length: 3,
+                compartments: 2
+            })
+        );
+        assert_eq!(
+            table.compartments(4, "ab1d", 2),
+            Err(RucksackError::InvalidItem { line: 4, item: '1' })
+        );
+
+        // Multi-byte items are reported whole
+        let item = 'é';
+
+        assert_eq!(
+            table.compartments(5, "aé", 2),
+            Err(RucksackError::InvalidItem { line: 5, item })
+        );
+        assert_eq!(
+            table.compartments(6, "", 2),
+            Err(RucksackError::Empty { line: 6 })
+        );
+    }
+
+    #[test]
+    fn custom_alphabets_set_their_own_priorities() {
+        let table = PriorityTable::from_alphabet("αβγ\n0123").unwrap();
+
+        assert_eq!(table.priority('γ'), Some(3));
+        assert_eq!(table.priority('0'), Some(4));
+        assert_eq!(table.priority('a'), None);
+
+        // Multi-byte items are split by char, not by byte
+        let masks = table.compartments(1, "αβ0β", 2).unwrap();
+
+        assert_eq!(mask_priority(masks[0] & masks[1]), 2);
+        assert_eq!(table.items(masks[0] | masks[1]), "αβ0");
+    }
+
+    #[test]
+    fn rejects_bad_alphabets() {
+        assert_eq!(
+            PriorityTable::from_alphabet("abca"),
+            Err(AlphabetError::Duplicate('a'))
+        );
+        let too_many = (0..64u8).map(|i| (b'0' + i) as char).collect::<String>();
+
+        assert_eq!(
+            PriorityTable::from_alphabet(&too_many),
+            Err(AlphabetError::TooLarge(64))
+        );
+    }
+
+    #[test]
+    fn splits_into_any_number_of_compartments() {
+        let table = PriorityTable::default();
+
+        let masks = table.compartments(1, "abacadbd", 4).unwrap();
+
+        assert_eq!(masks.len(), 4);
+        assert_eq!(table.items(shared_by(&masks, 4)), "");
+        assert_eq!(table.items(shared_by(&masks, 3)), "a");
+        assert_eq!(table.items(shared_by(&masks, 2)), "abd");
+        assert_eq!(
+            table.compartments(2, "abcde", 3),
+            Err(RucksackError::UnevenLength {
+                line: 2,
+                length: 5,
+                compartments: 3
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn masks_hold_exactly_the_items_given(items in "[a-zA-Z]{0,40}") {
+            let table = PriorityTable::default();
+
+            let mut expected = items.chars().collect::<Vec<char>>();
+
+            expected.sort_by_key(|c| table.priority(*c));
+            expected.dedup();
+
+            let mask = table.mask(1, &items).unwrap();
+
+            prop_assert_eq!(table.items(mask), expected.into_iter().collect::<String>());
+            prop_assert_eq!(mask & 1, 0);
+        }
+
+        #[test]
+        fn compartments_cover_the_whole_rucksack(items in "[a-zA-Z]{1,12}", k in 1usize..5) {
+            let table = PriorityTable::default();
+            let rucksack = items.repeat(k);
+
+            let masks = table.compartments(1, &rucksack, k).unwrap();
+
+            prop_assert_eq!(masks.len(), k);
+            prop_assert_eq!(
+                masks.iter().fold(0, |all, mask| all | mask),
+                table.mask(1, &rucksack).unwrap()
+            );
+        }
+    }
+}
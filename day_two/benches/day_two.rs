@@ -0,0 +1,58 @@
+#[path = "../src/generator.rs"]
+mod generator;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_two::mapping::Mapping;
+use day_two::rules::Rules;
+use day_two::{calculate_naive_score, calculate_proper_score};
+use generator::generate;
+
+const SAMPLE: &str = "A Y
+B X
+C Z";
+
+fn bench(c: &mut Criterion, name: &str, input: &str) {
+    let rules = Rules::classic();
+    let mapping = Mapping::naive();
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| {
+        b.iter(|| {
+            black_box(input)
+                .lines()
+                .enumerate()
+                .map(|(i, round)| mapping.decode(&rules, i + 1, round))
+                .collect::<Result<Vec<_>, _>>()
+        })
+    });
+    group.bench_function("part one", |b| {
+        b.iter(|| {
+            black_box(input)
+                .lines()
+                .enumerate()
+                .map(|(i, round)| calculate_naive_score(&rules, i + 1, round))
+                .sum::<Result<i64, _>>()
+        })
+    });
+    group.bench_function("part two", |b| {
+        b.iter(|| {
+            black_box(input)
+                .lines()
+                .enumerate()
+                .map(|(i, round)| calculate_proper_score(&rules, i + 1, round))
+                .sum::<Result<i64, _>>()
+        })
+    });
+
+    group.finish();
+}
+
+fn day_two(c: &mut Criterion) {
+    bench(c, "day_two/sample", SAMPLE);
+    bench(c, "day_two/scaled", &generate(25_000, 2022));
+}
+
+criterion_group!(benches, day_two);
+criterion_main!(benches);
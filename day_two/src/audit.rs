@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    mapping::{Mapping, MappingError},
+    rules::Rules,
+    RoundRecord, Shape,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditLine {
+    // 1-based line of the round in the guide
+    pub line: usize,
+    pub round: String,
+    pub naive: RoundRecord,
+    pub proper: RoundRecord,
+}
+
+impl AuditLine {
+    // Whether the two readings of the guide would have us play differently
+    pub fn disagrees(&self) -> bool {
+        self.naive.player != self.proper.player
+    }
+}
+
+// Reads every round of the guide both ways, with the opponent column decoded
+// by `opponent`
+pub fn audit_guide(
+    rules: &Rules,
+    opponent: &BTreeMap<String, Shape>,
+    guide: &str,
+) -> Result<Vec<AuditLine>, MappingError> {
+    let naive = Mapping {
+        opponent: opponent.clone(),
+        ..Mapping::naive()
+    };
+    let proper = Mapping {
+        opponent: opponent.clone(),
+        ..Mapping::proper()
+    };
+
+    guide
+        .lines()
+        .enumerate()
+        .map(|(i, round)| {
+            Ok(AuditLine {
+                line: i + 1,
+                round: round.to_string(),
+                naive: naive.record(rules, i + 1, round)?,
+                proper: proper.record(rules, i + 1, round)?,
+            })
+        })
+        .collect()
+}
+
+fn describe(rules: &Rules, record: &RoundRecord) -> String {
+    format!(
+        "{} vs {} -> {:?} ({} + {} = {})",
+        rules.name(&record.player),
+        rules.name(&record.opponent),
+        record.outcome,
+        record.shape_points,
+        record.outcome_points,
+        record.score()
+    )
+}
+
+// Lists the rounds where both readings disagree, marked with `*`, or every
+// round when `all` is set, followed by the totals
+pub fn format_audit(rules: &Rules, audit: &[AuditLine], all: bool) -> String {
+    let mut lines = audit
+        .iter()
+        .filter(|line| all || line.disagrees())
+        .map(|line| {
+            format!(
+                "{} {:>5} {}  naive: {}  proper: {}",
+                if line.disagrees() { "*" } else { " " },
+                line.line,
+                line.round,
+                describe(rules, &line.naive),
+                describe(rules, &line.proper)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let naive: i64 = audit.iter().map(|line| line.naive.score()).sum();
+    let proper: i64 = audit.iter().map(|line| line.proper.score()).sum();
+    let disagreements = audit.iter().filter(|line| line.disagrees()).count();
+
+    lines.push(format!(
+        "{} of {} rounds disagree, naive score: {}, proper score: {}",
+        disagreements,
+        audit.len(),
+        naive,
+        proper
+    ));
+
+    lines.join("\n")
+}
+
+// A CSV field as RFC 4180 has it: quoted, with quotes doubled, when it holds a
+// comma, a quote or a line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn format_csv(rules: &Rules, audit: &[AuditLine]) -> String {
+    let mut csv = String::from(
+        "line,opponent,naive_player,naive_outcome,naive_shape_points,naive_outcome_points,\
+         proper_player,proper_outcome,proper_shape_points,proper_outcome_points,disagree\n",
+    );
+
+    for line in audit {
+        let record = |record: &RoundRecord| {
+            format!(
+                "{},{:?},{},{}",
+                csv_field(rules.name(&record.player)),
+                record.outcome,
+                record.shape_points,
+                record.outcome_points
+            )
+        };
+
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            line.line,
+            csv_field(rules.name(&line.naive.opponent)),
+            record(&line.naive),
+            record(&line.proper),
+            line.disagrees()
+        ));
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::ShapeRule;
+
+    const INPUT: &str = "A Y
+B X
+C Z";
+
+    #[test]
+    fn records_both_readings_of_each_round() {
+        let rules = Rules::classic();
+        let audit = audit_guide(&rules, &Mapping::naive().opponent, INPUT).unwrap();
+
+        let first = &audit[0];
+
+        assert_eq!(
+            (first.naive.shape_points, first.naive.outcome_points),
+            (2, 6)
+        );
+        assert_eq!(
+            (first.proper.shape_points, first.proper.outcome_points),
+            (1, 3)
+        );
+
+        // B X means Rock either way, as that is what loses to Paper
+        assert_eq!(
+            audit
+                .iter()
+                .map(AuditLine::disagrees)
+                .collect::<Vec<bool>>(),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn exports_csv() {
+        let rules = Rules::classic();
+        let csv = format_csv(
+            &rules,
+            &audit_guide(&rules, &Mapping::naive().opponent, "A Y").unwrap(),
+        );
+
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("1,Rock,Paper,Win,2,6,Rock,Draw,1,3,true")
+        );
+    }
+
+    #[test]
+    fn report_only_lists_disagreements_by_default() {
+        let rules = Rules::classic();
+        let audit = audit_guide(&rules, &Mapping::naive().opponent, "A Y\nB Y").unwrap();
+
+        let report = format_audit(&rules, &audit, false);
+
+        assert_eq!(report.lines().count(), 2);
+        assert!(report.ends_with("1 of 2 rounds disagree, naive score: 13, proper score: 9"));
+    }
+
+    #[test]
+    fn quotes_csv_fields() {
+        let shapes = ["Rock, the", "\"Paper\"", "Scissor"]
+            .into_iter()
+            .zip(1..)
+            .map(|(name, score)| ShapeRule {
+                name: name.to_string(),
+                score,
+            })
+            .collect();
+        let rules = Rules::new(shapes, [0, 3, 6]).unwrap();
+
+        let csv = format_csv(
+            &rules,
+            &audit_guide(&rules, &Mapping::naive().opponent, "A Y").unwrap(),
+        );
+
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("1,\"Rock, the\",\"\"\"Paper\"\"\",Win,2,6,\"Rock, the\",Draw,1,3,true")
+        );
+    }
+
+    #[test]
+    fn rejects_rounds_it_cant_read() {
+        let rules = Rules::classic();
+
+        assert_eq!(
+            audit_guide(&rules, &Mapping::naive().opponent, "A Y\nA"),
+            Err(MappingError::UnknownLetter {
+                line: 2,
+                letter: "".to_string()
+            })
+        );
+    }
+}
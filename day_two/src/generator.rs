@@ -0,0 +1,17 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// A strategy guide of `rounds` rounds using the classic A-C and X-Z columns
+pub fn generate(rounds: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    (0..rounds)
+        .map(|_| {
+            let opponent = rng.gen_range(b'A'..=b'C') as char;
+            let player = rng.gen_range(b'X'..=b'Z') as char;
+
+            format!("{} {}", opponent, player)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
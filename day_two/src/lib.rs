@@ -0,0 +1,164 @@
+pub mod audit;
+#[cfg(test)]
+mod generator;
+pub mod mapping;
+pub mod rules;
+pub mod tournament;
+
+use std::error::Error;
+
+use common::normalize::normalize;
+use common::Solution;
+
+use mapping::{Mapping, MappingError};
+use rules::Rules;
+
+// Position of the shape in the cycle of the `Rules` in play
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Lost,
+    Draw,
+    Win,
+}
+
+pub fn calculate_outcome(rules: &Rules, round: (&Shape, &Shape)) -> Outcome {
+    let (opponent, player) = round;
+
+    rules.outcome(opponent, player)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundRecord {
+    pub opponent: Shape,
+    pub player: Shape,
+    pub outcome: Outcome,
+    pub shape_points: i32,
+    pub outcome_points: i32,
+}
+
+impl RoundRecord {
+    pub(crate) fn new(rules: &Rules, opponent: Shape, player: Shape, outcome: Outcome) -> Self {
+        Self {
+            shape_points: rules.shape_score(&player),
+            outcome_points: rules.outcome_score(&outcome),
+            opponent,
+            player,
+            outcome,
+        }
+    }
+
+    pub fn score(&self) -> i64 {
+        i64::from(self.shape_points) + i64::from(self.outcome_points)
+    }
+}
+
+pub fn record_naive_round(
+    rules: &Rules,
+    line: usize,
+    round: &str,
+) -> Result<RoundRecord, MappingError> {
+    Mapping::naive().record(rules, line, round)
+}
+
+pub fn record_proper_round(
+    rules: &Rules,
+    line: usize,
+    round: &str,
+) -> Result<RoundRecord, MappingError> {
+    Mapping::proper().record(rules, line, round)
+}
+
+pub fn calculate_naive_score(rules: &Rules, line: usize, round: &str) -> Result<i64, MappingError> {
+    Ok(record_naive_round(rules, line, round)?.score())
+}
+
+pub fn calculate_proper_score(
+    rules: &Rules,
+    line: usize,
+    round: &str,
+) -> Result<i64, MappingError> {
+    Ok(record_proper_round(rules, line, round)?.score())
+}
+
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    fn part_one(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let rules = Rules::classic();
+
+        Ok(Mapping::naive()
+            .score_guide(&rules, &normalize(input))?
+            .to_string())
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let rules = Rules::classic();
+
+        Ok(Mapping::proper()
+            .score_guide(&rules, &normalize(input))?
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::generate;
+
+    const INPUT: &str = "A Y
+B X
+C Z";
+
+    #[test]
+    fn naive_score_of_sample() {
+        let rules = Rules::classic();
+
+        let score = INPUT
+            .lines()
+            .enumerate()
+            .map(|(i, round)| calculate_naive_score(&rules, i + 1, round))
+            .sum::<Result<i64, _>>();
+
+        assert_eq!(score, Ok(15));
+    }
+
+    #[test]
+    fn proper_score_of_sample() {
+        let rules = Rules::classic();
+
+        let score = INPUT
+            .lines()
+            .enumerate()
+            .map(|(i, round)| calculate_proper_score(&rules, i + 1, round))
+            .sum::<Result<i64, _>>();
+
+        assert_eq!(score, Ok(12));
+    }
+
+    #[test]
+    fn rounds_outside_the_mapping_are_errors() {
+        let rules = Rules::classic();
+
+        assert_eq!(
+            record_naive_round(&rules, 3, "A W"),
+            Err(MappingError::UnknownLetter {
+                line: 3,
+                letter: "W".to_string()
+            })
+        );
+        assert!(record_proper_round(&rules, 1, "A").is_err());
+    }
+
+    #[test]
+    fn generates_decodable_guides() {
+        let guide = generate(500, 3);
+
+        assert_eq!(guide.lines().count(), 500);
+        assert!(Mapping::naive()
+            .score_guide(&Rules::classic(), &guide)
+            .is_ok());
+    }
+}
@@ -1,113 +1,189 @@
-use std::fs;
-
-
-#[derive(Debug, Clone)]
-#[repr(i32)]
-enum Shape {
-    // A, X (naively)
-    Rock = 1,
-    // B, Y (naively)
-    Paper = 2,
-    // C, Z (naively)
-    Scissor = 3,
+use std::{env, fs};
+
+use common::normalize::normalize;
+use common::{inputs::read_input, local_input};
+
+use day_two::audit::{audit_guide, format_audit, format_csv};
+use day_two::mapping::{parse_column, rank_interpretations, Mapping};
+use day_two::rules::Rules;
+use day_two::tournament::{tournament, Strategy};
+use day_two::{calculate_naive_score, calculate_proper_score, Shape};
+
+fn flag<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter().position(|arg| arg == name).map(|i| {
+        args.get(i + 1)
+            .unwrap_or_else(|| panic!("Expected a value for {}", name))
+            .as_str()
+    })
 }
 
-impl Shape {
-    pub fn from_str(m: &str) -> Self {
-        match m {
-            "A" | "X" => Self::Rock,
-            "B" | "Y" => Self::Paper,
-            "C" | "Z" => Self::Scissor,
-            _ => panic!("Invalid move supplied: {}", m)
-        }
+// `--mapping <file>` reads column mappings from <file>, and `--opponent <spec>`
+// and `--player <spec>` (e.g. `--player X=lose,Y=draw,Z=win`) override a column
+fn read_mapping(args: &[String], rules: &Rules) -> Option<Mapping> {
+    let file = flag(args, "--mapping");
+    let opponent = flag(args, "--opponent");
+    let player = flag(args, "--player");
+
+    if file.is_none() && opponent.is_none() && player.is_none() {
+        return None;
     }
-}
 
-#[derive(Debug)]
-#[repr(i32)]
-enum Outcome {
-    Lost = 0,
-    Draw = 3,
-    Win = 6,
-}
+    let mapping = file
+        .map(|file| fs::read_to_string(file).expect("Unable to load mapping"))
+        .map(|mapping| Mapping::from_str(rules, &normalize(&mapping)))
+        .unwrap_or_else(|| Ok(Mapping::naive()))
+        .and_then(|mut mapping| {
+            if let Some(spec) = opponent {
+                mapping.set_opponent(rules, spec)?;
+            }
 
-impl Outcome {
-    pub fn from_str(l: &str) -> Self {
-        match l {
-            "X" => Outcome::Lost,
-            "Y" => Outcome::Draw,
-            "Z" => Outcome::Win,
-            _ => panic!("Invalid outcome given: {}", l)
-        }
-    }
+            if let Some(spec) = player {
+                mapping.player = parse_column(rules, spec)?;
+            }
+
+            Ok(mapping)
+        })
+        .unwrap_or_else(|err| panic!("Invalid mapping: {}", err));
+
+    Some(mapping)
 }
 
-fn calculate_outcome(round: (&Shape, &Shape)) -> Outcome {
-    match round {
-        (Shape::Rock, Shape::Scissor)
-        | (Shape::Scissor, Shape::Paper)
-        | (Shape::Paper, Shape::Rock) => Outcome::Lost,
-        (Shape::Scissor, Shape::Rock)
-        | (Shape::Rock, Shape::Paper)
-        | (Shape::Paper, Shape::Scissor) => Outcome::Win,
-        _ => Outcome::Draw
+// `simulate [--rounds <n>] [--seed <n>]` plays a set of strategies against
+// the guide's opponent column
+fn simulate(args: &[String], rules: &Rules, mapping: &Mapping, stratagem: &str) {
+    let rounds = flag(args, "--rounds")
+        .map(|n| n.parse::<usize>().expect("Invalid number of rounds"))
+        .unwrap_or(stratagem.lines().count());
+    let seed = flag(args, "--seed")
+        .map(|n| n.parse::<u64>().expect("Invalid seed"))
+        .unwrap_or(2022);
+
+    let rounds_played = stratagem
+        .lines()
+        .enumerate()
+        .map(|(i, round)| mapping.decode(rules, i + 1, round))
+        .collect::<Result<Vec<(Shape, Shape)>, _>>()
+        .unwrap_or_else(|err| panic!("Invalid guide: {}", err));
+
+    let (opponent, guide): (Vec<Shape>, Vec<Shape>) = rounds_played.into_iter().unzip();
+
+    let (opponent, guide) = Strategy::fixed(opponent)
+        .and_then(|opponent| Ok((opponent, Strategy::fixed(guide)?)))
+        .unwrap_or_else(|err| panic!("Invalid guide: {}", err));
+
+    let players = vec![
+        ("guide".to_string(), guide),
+        ("random".to_string(), Strategy::random(seed)),
+        (
+            "frequency counter".to_string(),
+            Strategy::frequency_counter(rules),
+        ),
+        ("beat last move".to_string(), Strategy::beat_last()),
+    ];
+
+    let results = tournament(rules, &players, &opponent, rounds);
+
+    for (name, tally) in results {
+        println!(
+            "{:<18} score {:>6}  won {:>5}  drew {:>5}  lost {:>5}",
+            name, tally.score, tally.wins, tally.draws, tally.losses
+        );
     }
 }
 
-fn calculate_expected_move(strat: (&Shape, &Outcome)) -> Shape {
-    match strat {
-        (Shape::Rock, Outcome::Lost) => Shape::Scissor,
-        (Shape::Paper, Outcome::Lost) => Shape::Rock,
-        (Shape::Scissor, Outcome::Lost) => Shape::Paper,
-        (Shape::Rock, Outcome::Win) => Shape::Paper,
-        (Shape::Paper, Outcome::Win) => Shape::Scissor,
-        (Shape::Scissor, Outcome::Win) => Shape::Rock,
-        (opponent, _) => opponent.clone()
+fn main() {
+    let stratagem = read_input(local_input!(), 2022, 2)
+        .unwrap_or_else(|err| panic!("Unable to read input: {}", err));
+
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    // `--rules <file>` plays by the rules in <file> instead of the classic ones
+    let rules = match flag(&args, "--rules") {
+        Some(file) => {
+            let rules = fs::read_to_string(file).expect("Unable to load rules");
+
+            normalize(&rules)
+                .parse::<Rules>()
+                .unwrap_or_else(|err| panic!("Invalid rules: {}", err))
+        }
+        None => Rules::classic(),
+    };
+
+    let mapping = read_mapping(&args, &rules);
+
+    // `best` scores every reading of the player column, best first, so it
+    // takes no `--player`, nor a mapping file with a player line
+    if args.first().map(String::as_str) == Some("best") {
+        let mapping = mapping.unwrap_or_else(Mapping::naive);
+
+        if flag(&args, "--player").is_some() || mapping.player != Mapping::naive().player {
+            panic!("best tries every reading of the player column, so it can't be given one");
+        }
+
+        let opponent = mapping.opponent;
+
+        let ranked = rank_interpretations(&rules, &opponent, &stratagem)
+            .unwrap_or_else(|err| panic!("Invalid guide: {}", err));
+
+        for (mapping, score) in ranked {
+            println!("{:>6}  {}", score, mapping.describe(&rules));
+        }
+
+        return;
     }
-}
 
-fn calculate_naive_score(round: &str) -> i32 {
-    let [opponent, player]: [Shape; 2] = round
-        .split_whitespace()
-        .take(2)
-        .map(Shape::from_str)
-        .collect::<Vec<Shape>>()
-        .try_into().unwrap();
+    // `audit [--all | --csv]` compares both readings of every round, with the
+    // opponent column read as `--mapping` or `--opponent` say
+    if args.first().map(String::as_str) == Some("audit") {
+        let opponent = mapping.unwrap_or_else(Mapping::naive).opponent;
 
-    let outcome = calculate_outcome((&opponent, &player));
+        let audit = audit_guide(&rules, &opponent, &stratagem)
+            .unwrap_or_else(|err| panic!("Invalid guide: {}", err));
 
-    player as i32 + outcome as i32
-}
+        if args.iter().any(|arg| arg == "--csv") {
+            print!("{}", format_csv(&rules, &audit));
+        } else {
+            let all = args.iter().any(|arg| arg == "--all");
 
-fn calculate_proper_score(round: &str) -> i32 {
-    let [opponent, outcome]: [&str; 2] = round
-        .split_whitespace()
-        .take(2)
-        .collect::<Vec<&str>>()
-        .try_into().unwrap();
+            println!("{}", format_audit(&rules, &audit, all));
+        }
 
-    let opponent = Shape::from_str(opponent);
-    let outcome = Outcome::from_str(outcome);
+        return;
+    }
 
-    let right = calculate_expected_move((&opponent, &outcome));
+    if args.first().map(String::as_str) == Some("simulate") {
+        simulate(
+            &args,
+            &rules,
+            &mapping.unwrap_or_else(Mapping::naive),
+            &stratagem,
+        );
+        return;
+    }
 
-    right as i32 + outcome as i32
-}
+    if let Some(mapping) = mapping {
+        let score = mapping
+            .score_guide(&rules, &stratagem)
+            .unwrap_or_else(|err| panic!("Invalid guide: {}", err));
 
-fn main() {
-    let stratagem = fs::read_to_string("input.txt").expect("Unable to load input data");
+        println!("score: {}", score);
+        return;
+    }
 
-    let naive_score: i32 = stratagem
+    let naive_score = stratagem
         .lines()
-        .map(calculate_naive_score)
-        .sum();
+        .enumerate()
+        .map(|(i, round)| calculate_naive_score(&rules, i + 1, round))
+        .sum::<Result<i64, _>>()
+        .unwrap_or_else(|err| panic!("Invalid guide: {}", err));
 
-    let proper_score: i32 = stratagem
+    let proper_score = stratagem
         .lines()
-        .map(calculate_proper_score)
-        .sum();
+        .enumerate()
+        .map(|(i, round)| calculate_proper_score(&rules, i + 1, round))
+        .sum::<Result<i64, _>>()
+        .unwrap_or_else(|err| panic!("Invalid guide: {}", err));
 
     println!("naive score: {}", naive_score);
     println!("proper score: {}", proper_score);
 }
-
@@ -0,0 +1,393 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt,
+};
+
+use crate::{rules::Rules, Outcome, RoundRecord, Shape};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MappingError {
+    InvalidLine { line: usize, content: String },
+    // A letter decodes to something that is neither a shape nor an outcome
+    UnknownValue(String),
+    // A column can decode to shapes or to outcomes, not both
+    MixedColumn(String),
+    // A guide round uses a letter the mapping doesn't know about
+    UnknownLetter { line: usize, letter: String },
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingError::InvalidLine { line, content } => {
+                write!(f, "line {}: unable to parse {:?}", line, content)
+            }
+            MappingError::UnknownValue(value) => {
+                write!(f, "{:?} is neither a shape nor an outcome", value)
+            }
+            MappingError::MixedColumn(spec) => {
+                write!(f, "{:?} mixes shapes and outcomes", spec)
+            }
+            MappingError::UnknownLetter { line, letter } => {
+                write!(f, "line {}: no mapping for {:?}", line, letter)
+            }
+        }
+    }
+}
+
+impl Error for MappingError {}
+
+// How the letters of the second column decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerColumn {
+    Shapes(BTreeMap<String, Shape>),
+    Outcomes(BTreeMap<String, Outcome>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub opponent: BTreeMap<String, Shape>,
+    pub player: PlayerColumn,
+}
+
+enum Value {
+    Shape(Shape),
+    Outcome(Outcome),
+}
+
+fn parse_value(rules: &Rules, value: &str) -> Result<Value, MappingError> {
+    if let Some(shape) = rules.shape_named(value) {
+        return Ok(Value::Shape(shape));
+    }
+
+    match value.to_ascii_lowercase().as_str() {
+        "lose" | "lost" | "loss" => Ok(Value::Outcome(Outcome::Lost)),
+        "draw" => Ok(Value::Outcome(Outcome::Draw)),
+        "win" => Ok(Value::Outcome(Outcome::Win)),
+        _ => Err(MappingError::UnknownValue(value.to_string())),
+    }
+}
+
+// Reads a column spec such as `X=Rock Y=Paper Z=Scissor` or `X=lose,Y=draw,Z=win`
+pub fn parse_column(rules: &Rules, spec: &str) -> Result<PlayerColumn, MappingError> {
+    let mut shapes = BTreeMap::new();
+    let mut outcomes = BTreeMap::new();
+
+    for entry in spec.split([' ', ',']).filter(|entry| !entry.is_empty()) {
+        let (letter, value) = entry
+            .split_once('=')
+            .ok_or_else(|| MappingError::UnknownValue(entry.to_string()))?;
+
+        match parse_value(rules, value)? {
+            Value::Shape(shape) => {
+                shapes.insert(letter.to_string(), shape);
+            }
+            Value::Outcome(outcome) => {
+                outcomes.insert(letter.to_string(), outcome);
+            }
+        }
+    }
+
+    match (shapes.is_empty(), outcomes.is_empty()) {
+        (_, true) => Ok(PlayerColumn::Shapes(shapes)),
+        (true, false) => Ok(PlayerColumn::Outcomes(outcomes)),
+        (false, false) => Err(MappingError::MixedColumn(spec.to_string())),
+    }
+}
+
+impl Mapping {
+    // The guide read naively: A/B/C and X/Y/Z are the first three shapes,
+    // which every `Rules` has
+    pub fn naive() -> Self {
+        let letters = |letters: [&str; 3]| {
+            letters
+                .iter()
+                .enumerate()
+                .map(|(i, letter)| (letter.to_string(), Shape(i)))
+                .collect::<BTreeMap<String, Shape>>()
+        };
+
+        Self {
+            opponent: letters(["A", "B", "C"]),
+            player: PlayerColumn::Shapes(letters(["X", "Y", "Z"])),
+        }
+    }
+
+    // What the guide actually means: X/Y/Z are the outcomes to lose, draw and win
+    pub fn proper() -> Self {
+        let outcomes = [
+            ("X", Outcome::Lost),
+            ("Y", Outcome::Draw),
+            ("Z", Outcome::Win),
+        ]
+        .into_iter()
+        .map(|(letter, outcome)| (letter.to_string(), outcome))
+        .collect();
+
+        Self {
+            player: PlayerColumn::Outcomes(outcomes),
+            ..Self::naive()
+        }
+    }
+
+    // Reads a mapping written as
+    //
+    //     opponent A=Rock B=Paper C=Scissor
+    //     player X=lose Y=draw Z=win
+    //
+    // Either line can be left out to keep the naive mapping for that column.
+    pub fn from_str(rules: &Rules, input: &str) -> Result<Self, MappingError> {
+        let mut mapping = Self::naive();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match line.split_once(' ') {
+                Some(("opponent", spec)) => mapping.set_opponent(rules, spec)?,
+                Some(("player", spec)) => mapping.player = parse_column(rules, spec)?,
+                _ => {
+                    return Err(MappingError::InvalidLine {
+                        line: i + 1,
+                        content: line.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(mapping)
+    }
+
+    pub fn set_opponent(&mut self, rules: &Rules, spec: &str) -> Result<(), MappingError> {
+        match parse_column(rules, spec)? {
+            PlayerColumn::Shapes(shapes) => {
+                self.opponent = shapes;
+                Ok(())
+            }
+            PlayerColumn::Outcomes(_) => Err(MappingError::MixedColumn(spec.to_string())),
+        }
+    }
+
+    // The shape played by each side in a round
+    pub fn decode(
+        &self,
+        rules: &Rules,
+        line: usize,
+        round: &str,
+    ) -> Result<(Shape, Shape), MappingError> {
+        let mut letters = round.split_whitespace();
+
+        let opponent_letter = letters.next().unwrap_or_default();
+        let player_letter = letters.next().unwrap_or_default();
+
+        let unknown = |letter: &str| MappingError::UnknownLetter {
+            line,
+            letter: letter.to_string(),
+        };
+
+        let opponent = self
+            .opponent
+            .get(opponent_letter)
+            .cloned()
+            .ok_or_else(|| unknown(opponent_letter))?;
+
+        let player = match &self.player {
+            PlayerColumn::Shapes(shapes) => shapes
+                .get(player_letter)
+                .cloned()
+                .ok_or_else(|| unknown(player_letter))?,
+            PlayerColumn::Outcomes(outcomes) => {
+                let outcome = outcomes
+                    .get(player_letter)
+                    .ok_or_else(|| unknown(player_letter))?;
+
+                rules.expected_move(&opponent, outcome)
+            }
+        };
+
+        Ok((opponent, player))
+    }
+
+    // The round as played under this mapping, scored
+    pub fn record(
+        &self,
+        rules: &Rules,
+        line: usize,
+        round: &str,
+    ) -> Result<RoundRecord, MappingError> {
+        let (opponent, player) = self.decode(rules, line, round)?;
+        let outcome = rules.outcome(&opponent, &player);
+
+        Ok(RoundRecord::new(rules, opponent, player, outcome))
+    }
+
+    pub fn score_guide(&self, rules: &Rules, guide: &str) -> Result<i64, MappingError> {
+        guide
+            .lines()
+            .enumerate()
+            .map(|(i, round)| {
+                let (opponent, player) = self.decode(rules, i + 1, round)?;
+
+                Ok(rules.score(&player, &rules.outcome(&opponent, &player)))
+            })
+            .sum()
+    }
+
+    pub fn describe(&self, rules: &Rules) -> String {
+        match &self.player {
+            PlayerColumn::Shapes(shapes) => shapes
+                .iter()
+                .map(|(letter, shape)| format!("{}={}", letter, rules.name(shape)))
+                .collect::<Vec<String>>()
+                .join(" "),
+            PlayerColumn::Outcomes(outcomes) => outcomes
+                .iter()
+                .map(|(letter, outcome)| format!("{}={:?}", letter, outcome))
+                .collect::<Vec<String>>()
+                .join(" "),
+        }
+    }
+}
+
+// Every ordered selection of `k` items
+fn permutations<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+
+    (0..items.len())
+        .flat_map(|i| {
+            let mut rest = items.to_vec();
+            let item = rest.remove(i);
+
+            permutations(&rest, k - 1).into_iter().map(move |mut tail| {
+                tail.insert(0, item.clone());
+                tail
+            })
+        })
+        .collect()
+}
+
+fn assign<T>(letters: &BTreeSet<String>, values: Vec<T>) -> BTreeMap<String, T> {
+    letters.iter().cloned().zip(values).collect()
+}
+
+// Scores the guide under every way of reading the player column's letters as
+// distinct shapes, and as distinct outcomes, best first. For the classic
+// rules and X/Y/Z that is 6 permutations of each.
+pub fn rank_interpretations(
+    rules: &Rules,
+    opponent: &BTreeMap<String, Shape>,
+    guide: &str,
+) -> Result<Vec<(Mapping, i64)>, MappingError> {
+    let letters = guide
+        .lines()
+        .filter_map(|round| round.split_whitespace().nth(1))
+        .map(String::from)
+        .collect::<BTreeSet<String>>();
+
+    let k = letters.len();
+
+    let shapes = permutations(&(0..rules.len()).map(Shape).collect::<Vec<Shape>>(), k)
+        .into_iter()
+        .map(|shapes| PlayerColumn::Shapes(assign(&letters, shapes)));
+
+    let outcomes = permutations(&[Outcome::Lost, Outcome::Draw, Outcome::Win], k)
+        .into_iter()
+        .map(|outcomes| PlayerColumn::Outcomes(assign(&letters, outcomes)));
+
+    let mut ranked = shapes
+        .chain(outcomes)
+        .map(|player| {
+            let mapping = Mapping {
+                opponent: opponent.clone(),
+                player,
+            };
+
+            mapping
+                .score_guide(rules, guide)
+                .map(|score| (mapping, score))
+        })
+        .collect::<Result<Vec<(Mapping, i64)>, MappingError>>()?;
+
+    ranked.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+
+    Ok(ranked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "A Y
+B X
+C Z";
+
+    #[test]
+    fn naive_and_outcome_mappings_match_both_scores() {
+        let rules = Rules::classic();
+
+        assert_eq!(Mapping::naive().score_guide(&rules, INPUT), Ok(15));
+
+        let proper = Mapping::from_str(&rules, "player X=lose Y=draw Z=win").unwrap();
+
+        assert_eq!(proper, Mapping::proper());
+        assert_eq!(proper.score_guide(&rules, INPUT), Ok(12));
+    }
+
+    #[test]
+    fn totals_of_extreme_scores_dont_overflow() {
+        let rules = "shape A 2147483647\nshape B 2\nshape C 3\noutcomes 0 3 2147483647"
+            .parse::<Rules>()
+            .unwrap();
+
+        // Three rounds of playing A and winning against C
+        assert_eq!(
+            Mapping::naive().score_guide(&rules, "C X\nC X\nC X"),
+            Ok(3 * 4294967294)
+        );
+    }
+
+    #[test]
+    fn reads_permuted_columns() {
+        let rules = Rules::classic();
+
+        let mapping = Mapping::from_str(
+            &rules,
+            "opponent A=Scissor B=Rock C=Paper\nplayer X=Paper,Y=Scissor,Z=Rock",
+        )
+        .unwrap();
+
+        assert_eq!(mapping.decode(&rules, 1, "A Y"), Ok((Shape(2), Shape(2))));
+        assert_eq!(
+            mapping.decode(&rules, 2, "D Y"),
+            Err(MappingError::UnknownLetter {
+                line: 2,
+                letter: "D".to_string()
+            })
+        );
+        assert_eq!(
+            Mapping::from_str(&rules, "player X=Rock Y=win"),
+            Err(MappingError::MixedColumn("X=Rock Y=win".to_string()))
+        );
+    }
+
+    #[test]
+    fn ranks_all_twelve_interpretations() {
+        let rules = Rules::classic();
+
+        let ranked = rank_interpretations(&rules, &Mapping::naive().opponent, INPUT).unwrap();
+
+        assert_eq!(ranked.len(), 12);
+
+        let (best, score) = &ranked[0];
+
+        // Always winning: A Y -> Paper, B X -> Scissor, C Z -> Rock
+        assert_eq!(*score, 24);
+        assert_eq!(best.describe(&rules), "X=Scissor Y=Paper Z=Rock");
+    }
+}
@@ -0,0 +1,338 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::{Outcome, Shape};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RulesError {
+    // Only an odd number of shapes lets every shape beat exactly as many
+    // shapes as it loses to
+    EvenShapeCount(usize),
+    // The guide's letters name three shapes, so fewer can't be played
+    TooFewShapes(usize),
+    InvalidLine { line: usize, content: String },
+    MissingOutcomes,
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::EvenShapeCount(n) => {
+                write!(f, "expected an odd number of shapes, found {}", n)
+            }
+            RulesError::TooFewShapes(n) => {
+                write!(f, "expected at least 3 shapes, found {}", n)
+            }
+            RulesError::InvalidLine { line, content } => {
+                write!(f, "line {}: unable to parse {:?}", line, content)
+            }
+            RulesError::MissingOutcomes => write!(f, "no outcome scores given"),
+        }
+    }
+}
+
+impl Error for RulesError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeRule {
+    pub name: String,
+    pub score: i32,
+}
+
+// Shapes are listed in a cycle where every shape beats the shapes an odd
+// number of steps before it, and loses to the ones an even number of steps
+// before it. Rock, Paper, Scissors is the classic cycle of three, and
+// Rock, Paper, Scissors, Spock, Lizard gives the five shape variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    shapes: Vec<ShapeRule>,
+    // Points for losing, drawing and winning a round
+    outcome_scores: [i32; 3],
+}
+
+impl Rules {
+    pub fn new(shapes: Vec<ShapeRule>, outcome_scores: [i32; 3]) -> Result<Self, RulesError> {
+        if shapes.len().is_multiple_of(2) {
+            return Err(RulesError::EvenShapeCount(shapes.len()));
+        }
+
+        if shapes.len() < 3 {
+            return Err(RulesError::TooFewShapes(shapes.len()));
+        }
+
+        Ok(Self {
+            shapes,
+            outcome_scores,
+        })
+    }
+
+    pub fn classic() -> Self {
+        let shapes = [("Rock", 1), ("Paper", 2), ("Scissor", 3)]
+            .into_iter()
+            .map(|(name, score)| ShapeRule {
+                name: name.to_string(),
+                score,
+            })
+            .collect();
+
+        Self::new(shapes, [0, 3, 6]).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn name(&self, shape: &Shape) -> &str {
+        &self.shapes[shape.0].name
+    }
+
+    pub fn shape_named(&self, name: &str) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.name.eq_ignore_ascii_case(name))
+            .map(Shape)
+    }
+
+    pub fn shape_score(&self, shape: &Shape) -> i32 {
+        self.shapes[shape.0].score
+    }
+
+    pub fn outcome_score(&self, outcome: &Outcome) -> i32 {
+        match outcome {
+            Outcome::Lost => self.outcome_scores[0],
+            Outcome::Draw => self.outcome_scores[1],
+            Outcome::Win => self.outcome_scores[2],
+        }
+    }
+
+    // Number of steps from `b` forward to `a` around the cycle
+    fn distance(&self, a: &Shape, b: &Shape) -> usize {
+        (a.0 + self.len() - b.0) % self.len()
+    }
+
+    // The outcome for the player
+    pub fn outcome(&self, opponent: &Shape, player: &Shape) -> Outcome {
+        match self.distance(player, opponent) {
+            0 => Outcome::Draw,
+            d if d % 2 == 1 => Outcome::Win,
+            _ => Outcome::Lost,
+        }
+    }
+
+    // The shape to play against `opponent` to get `outcome`. When several
+    // shapes would do, the closest one in the cycle is picked.
+    pub fn expected_move(&self, opponent: &Shape, outcome: &Outcome) -> Shape {
+        let step = match outcome {
+            Outcome::Draw => 0,
+            Outcome::Win => 1,
+            Outcome::Lost => self.len() - 1,
+        };
+
+        Shape((opponent.0 + step) % self.len())
+    }
+
+    // Scores given in the rules are i32s, so any two of them fit in an i64,
+    // as do the totals of any guide
+    pub fn score(&self, player: &Shape, outcome: &Outcome) -> i64 {
+        i64::from(self.shape_score(player)) + i64::from(self.outcome_score(outcome))
+    }
+}
+
+impl FromStr for Rules {
+    type Err = RulesError;
+
+    // Reads rules written as
+    //
+    //     shape Rock 1
+    //     shape Paper 2
+    //     shape Scissor 3
+    //     outcomes 0 3 6
+    //
+    // with shapes in cycle order. Blank lines and lines starting with `#` are
+    // ignored.
+    fn from_str(input: &str) -> Result<Self, RulesError> {
+        let mut shapes: Vec<ShapeRule> = vec![];
+        let mut outcome_scores: Option<[i32; 3]> = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || RulesError::InvalidLine {
+                line: i + 1,
+                content: line.to_string(),
+            };
+
+            let parts = line.split_whitespace().collect::<Vec<&str>>();
+
+            match parts.as_slice() {
+                ["shape", name, score] => shapes.push(ShapeRule {
+                    name: name.to_string(),
+                    score: score.parse().map_err(|_| invalid())?,
+                }),
+                ["outcomes", scores @ ..] => {
+                    outcome_scores = Some(
+                        scores
+                            .iter()
+                            .map(|s| s.parse::<i32>())
+                            .collect::<Result<Vec<i32>, _>>()
+                            .ok()
+                            .and_then(|scores| scores.try_into().ok())
+                            .ok_or_else(invalid)?,
+                    )
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Self::new(shapes, outcome_scores.ok_or(RulesError::MissingOutcomes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const SPOCK: &str = "
+# Rock, Paper, Scissors, Spock, Lizard
+shape Rock 1
+shape Paper 2
+shape Scissors 3
+shape Spock 4
+shape Lizard 5
+outcomes 0 3 6
+";
+
+    fn shape(rules: &Rules, name: &str) -> Shape {
+        rules.shape_named(name).unwrap()
+    }
+
+    #[test]
+    fn classic_rules_match_rock_paper_scissors() {
+        let rules = Rules::classic();
+        let [rock, paper, scissor] = [Shape(0), Shape(1), Shape(2)];
+
+        assert_eq!(rules.outcome(&rock, &paper), Outcome::Win);
+        assert_eq!(rules.outcome(&paper, &scissor), Outcome::Win);
+        assert_eq!(rules.outcome(&scissor, &rock), Outcome::Win);
+        assert_eq!(rules.outcome(&paper, &rock), Outcome::Lost);
+        assert_eq!(rules.outcome(&rock, &rock), Outcome::Draw);
+    }
+
+    #[test]
+    fn five_shapes_follow_spock_rules() {
+        let rules = Rules::from_str(SPOCK).unwrap();
+
+        let wins = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+
+        for (winner, loser) in wins {
+            let (winner, loser) = (shape(&rules, winner), shape(&rules, loser));
+
+            assert_eq!(rules.outcome(&loser, &winner), Outcome::Win);
+            assert_eq!(rules.outcome(&winner, &loser), Outcome::Lost);
+        }
+    }
+
+    #[test]
+    fn expected_move_gives_the_outcome() {
+        let rules = Rules::from_str(SPOCK).unwrap();
+
+        for opponent in (0..rules.len()).map(Shape) {
+            for outcome in [Outcome::Lost, Outcome::Draw, Outcome::Win] {
+                let player = rules.expected_move(&opponent, &outcome);
+
+                assert_eq!(rules.outcome(&opponent, &player), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_even_shape_counts() {
+        assert_eq!(
+            Rules::from_str("shape Rock 1\nshape Paper 2\noutcomes 0 3 6"),
+            Err(RulesError::EvenShapeCount(2))
+        );
+        assert_eq!(
+            Rules::from_str("shape Rock 1\noutcomes 0 3"),
+            Err(RulesError::InvalidLine {
+                line: 2,
+                content: "outcomes 0 3".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn extreme_scores_dont_overflow() {
+        let rules = Rules::from_str(
+            "shape A 2147483647\nshape B -2147483648\nshape C 0\noutcomes 0 3 2147483647",
+        )
+        .unwrap();
+
+        assert_eq!(rules.score(&Shape(0), &Outcome::Win), 4294967294);
+        assert_eq!(rules.score(&Shape(1), &Outcome::Lost), -2147483648);
+    }
+
+    #[test]
+    fn rejects_a_single_shape() {
+        assert_eq!(
+            Rules::from_str("shape Rock 1\noutcomes 0 3 6"),
+            Err(RulesError::TooFewShapes(1))
+        );
+    }
+
+    // Classic rules or a larger odd cycle, with a pair of shapes from it
+    fn rules_and_round() -> impl Strategy<Value = (Rules, Shape, Shape)> {
+        (1usize..6).prop_flat_map(|half| {
+            let count = half * 2 + 1;
+            let shapes = (0..count)
+                .map(|i| ShapeRule {
+                    name: format!("shape{}", i),
+                    score: i as i32 + 1,
+                })
+                .collect();
+            let rules = Rules::new(shapes, [0, 3, 6]).unwrap();
+
+            (Just(rules), 0..count, 0..count).prop_map(|(rules, a, b)| (rules, Shape(a), Shape(b)))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn expected_move_gives_the_outcome_in_any_cycle((rules, opponent, _) in rules_and_round()) {
+            for outcome in [Outcome::Lost, Outcome::Draw, Outcome::Win] {
+                let player = rules.expected_move(&opponent, &outcome);
+
+                prop_assert_eq!(rules.outcome(&opponent, &player), outcome);
+            }
+        }
+
+        #[test]
+        fn outcomes_are_mirrored((rules, a, b) in rules_and_round()) {
+            let mirrored = match rules.outcome(&a, &b) {
+                Outcome::Win => Outcome::Lost,
+                Outcome::Draw => Outcome::Draw,
+                Outcome::Lost => Outcome::Win,
+            };
+
+            prop_assert_eq!(rules.outcome(&b, &a), mirrored);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use std::{error::Error, fmt};
+
+use crate::{calculate_outcome, rules::Rules, Outcome, Shape};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum StrategyError {
+    // A fixed strategy has nothing to play without moves
+    NoMoves,
+}
+
+impl fmt::Display for StrategyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrategyError::NoMoves => write!(f, "a fixed strategy needs at least one move"),
+        }
+    }
+}
+
+impl Error for StrategyError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    // Plays the moves in order, starting over once they run out
+    Fixed { moves: Vec<Shape>, next: usize },
+    // xorshift64, so runs are reproducible from the seed
+    Random { state: u64 },
+    // Beats whatever the opponent has played most often so far
+    FrequencyCounter { counts: Vec<usize> },
+    // Beats whatever the opponent played last round
+    BeatLast { last: Option<Shape> },
+}
+
+impl Strategy {
+    pub fn fixed(moves: Vec<Shape>) -> Result<Self, StrategyError> {
+        if moves.is_empty() {
+            return Err(StrategyError::NoMoves);
+        }
+
+        Ok(Strategy::Fixed { moves, next: 0 })
+    }
+
+    pub fn random(seed: u64) -> Self {
+        // xorshift gets stuck on zero
+        Strategy::Random { state: seed.max(1) }
+    }
+
+    pub fn frequency_counter(rules: &Rules) -> Self {
+        Strategy::FrequencyCounter {
+            counts: vec![0; rules.len()],
+        }
+    }
+
+    pub fn beat_last() -> Self {
+        Strategy::BeatLast { last: None }
+    }
+
+    pub fn choose(&mut self, rules: &Rules) -> Shape {
+        match self {
+            Strategy::Fixed { moves, next } => {
+                let shape = moves[*next % moves.len()].clone();
+
+                *next += 1;
+
+                shape
+            }
+            Strategy::Random { state } => {
+                *state ^= *state << 13;
+                *state ^= *state >> 7;
+                *state ^= *state << 17;
+
+                Shape((*state % rules.len() as u64) as usize)
+            }
+            Strategy::FrequencyCounter { counts } => {
+                // Ties go to the shape earliest in the cycle
+                let most_played = (0..counts.len())
+                    .rev()
+                    .max_by_key(|i| counts[*i])
+                    .unwrap_or(0);
+
+                rules.expected_move(&Shape(most_played), &Outcome::Win)
+            }
+            Strategy::BeatLast { last } => match last {
+                Some(last) => rules.expected_move(last, &Outcome::Win),
+                None => Shape(0),
+            },
+        }
+    }
+
+    pub fn observe(&mut self, opponent: &Shape) {
+        match self {
+            Strategy::FrequencyCounter { counts } => counts[opponent.0] += 1,
+            Strategy::BeatLast { last } => *last = Some(opponent.clone()),
+            Strategy::Fixed { .. } | Strategy::Random { .. } => {}
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Tally {
+    pub score: i64,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Tally {
+    fn record(&mut self, rules: &Rules, player: &Shape, outcome: &Outcome) {
+        self.score += rules.score(player, outcome);
+
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Lost => self.losses += 1,
+        }
+    }
+}
+
+// Plays `rounds` rounds between fresh copies of both strategies, returning
+// the player's tally followed by the opponent's
+pub fn play(
+    rules: &Rules,
+    player: &Strategy,
+    opponent: &Strategy,
+    rounds: usize,
+) -> (Tally, Tally) {
+    let (mut player, mut opponent) = (player.clone(), opponent.clone());
+    let (mut player_tally, mut opponent_tally) = (Tally::default(), Tally::default());
+
+    for _ in 0..rounds {
+        let player_move = player.choose(rules);
+        let opponent_move = opponent.choose(rules);
+
+        let outcome = calculate_outcome(rules, (&opponent_move, &player_move));
+        let opponent_outcome = calculate_outcome(rules, (&player_move, &opponent_move));
+
+        player_tally.record(rules, &player_move, &outcome);
+        opponent_tally.record(rules, &opponent_move, &opponent_outcome);
+
+        player.observe(&opponent_move);
+        opponent.observe(&player_move);
+    }
+
+    (player_tally, opponent_tally)
+}
+
+// Plays every player against the same opponent, best score first
+pub fn tournament<'a>(
+    rules: &Rules,
+    players: &'a [(String, Strategy)],
+    opponent: &Strategy,
+    rounds: usize,
+) -> Vec<(&'a str, Tally)> {
+    let mut results = players
+        .iter()
+        .map(|(name, strategy)| (name.as_str(), play(rules, strategy, opponent, rounds).0))
+        .collect::<Vec<(&str, Tally)>>();
+
+    results.sort_by_key(|(_, tally)| std::cmp::Reverse(tally.score));
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_guide_moves_reproduce_the_naive_score() {
+        let rules = Rules::classic();
+
+        // A Y, B X, C Z
+        let opponent = Strategy::fixed(vec![Shape(0), Shape(1), Shape(2)]).unwrap();
+        let player = Strategy::fixed(vec![Shape(1), Shape(0), Shape(2)]).unwrap();
+
+        let (tally, opponent_tally) = play(&rules, &player, &opponent, 3);
+
+        assert_eq!(
+            tally,
+            Tally {
+                score: 15,
+                wins: 1,
+                draws: 1,
+                losses: 1
+            }
+        );
+        assert_eq!((opponent_tally.wins, opponent_tally.losses), (1, 1));
+    }
+
+    #[test]
+    fn adaptive_strategies_beat_a_constant_opponent() {
+        let rules = Rules::classic();
+        let rock = Strategy::fixed(vec![Shape(0)]).unwrap();
+
+        for strategy in [Strategy::frequency_counter(&rules), Strategy::beat_last()] {
+            let (tally, _) = play(&rules, &strategy, &rock, 10);
+
+            // Only the opening move is a guess
+            assert!(tally.wins >= 9, "{:?} won {}", strategy, tally.wins);
+        }
+    }
+
+    #[test]
+    fn random_play_is_reproducible() {
+        let rules = Rules::classic();
+        let rock = Strategy::fixed(vec![Shape(0)]).unwrap();
+
+        let first = play(&rules, &Strategy::random(42), &rock, 100);
+        let second = play(&rules, &Strategy::random(42), &rock, 100);
+
+        assert_eq!(first, second);
+        assert_eq!(first.0.wins + first.0.draws + first.0.losses, 100);
+    }
+
+    #[test]
+    fn fixed_strategies_need_moves() {
+        assert_eq!(Strategy::fixed(vec![]), Err(StrategyError::NoMoves));
+    }
+}
@@ -0,0 +1,16 @@
+#![no_main]
+
+use day_eight::{count_visible_trees, find_best_scenic_score, parse_forest};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok((forest, width, height)) = parse_forest(input) {
+        assert_eq!(forest.len(), width * height);
+
+        // Solving is quadratic in the width, so only small forests
+        if forest.len() <= 2500 {
+            count_visible_trees(&forest, width, height);
+            find_best_scenic_score(&forest, width, height);
+        }
+    }
+});
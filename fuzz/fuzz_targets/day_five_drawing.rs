@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_five::parse_raw_stacks;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = parse_raw_stacks(input);
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use day_five::Instruction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for line in input.lines() {
+        if let Ok(instruction) = line.parse::<Instruction>() {
+            // Printing and parsing again gives the same instruction
+            assert_eq!(
+                instruction.to_string().parse::<Instruction>(),
+                Ok(instruction)
+            );
+        }
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use common::Solution;
+use day_five::DayFive;
+use libfuzzer_sys::fuzz_target;
+
+// The whole path from drawing and instructions to the top crates, so moves
+// that don't fit the drawing reach the cranes
+fuzz_target!(|input: &str| {
+    let _ = DayFive.part_one(input);
+    let _ = DayFive.part_two(input);
+});
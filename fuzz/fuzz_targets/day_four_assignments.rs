@@ -0,0 +1,15 @@
+#![no_main]
+
+use day_four::{find_any_overlaps, find_complete_overlaps, group_into_assignments, Assignments};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let assignments = input
+        .lines()
+        .map(group_into_assignments)
+        .collect::<Result<Vec<Assignments>, _>>();
+
+    if let Ok(assignments) = assignments {
+        assert!(find_complete_overlaps(&assignments) <= find_any_overlaps(&assignments));
+    }
+});
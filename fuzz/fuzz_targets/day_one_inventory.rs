@@ -0,0 +1,17 @@
+#![no_main]
+
+use day_one::inventory::Inventories;
+use day_one::report::parse_elves;
+use day_one::top_elves::track_top_elves;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Stops by itself after the first error
+    for _ in Inventories::new(data) {}
+
+    let _ = track_top_elves(data, 3);
+
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = parse_elves(input);
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use common::Solution;
+use day_seven::{DaySeven, File};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for line in input.lines() {
+        let _ = File::try_from_str(line);
+    }
+
+    let _ = DaySeven.part_one(input);
+    let _ = DaySeven.part_two(input);
+});
@@ -0,0 +1,31 @@
+#![no_main]
+
+use day_six::decoder::decode;
+use day_six::search::{end_index_of_first_marker, Mode, SearchOptions};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = decode(data, 4);
+
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    for mode in [
+        Mode::Bytes,
+        Mode::Chars,
+        Mode::CharsAtByteOffsets,
+        Mode::Graphemes,
+    ] {
+        for skip_whitespace in [false, true] {
+            let options = SearchOptions {
+                mode,
+                skip_whitespace,
+            };
+
+            for n in [1, 4, 14] {
+                let _ = end_index_of_first_marker(input, n, options);
+            }
+        }
+    }
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use day_three::analytics::analyse;
+use day_three::priority::PriorityTable;
+use day_three::{part_one, part_two};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let table = PriorityTable::default();
+
+    let _ = part_one(&table, input);
+
+    for n in 1..=3 {
+        let _ = part_two(&table, input, n);
+        let _ = analyse(&table, input, n, n);
+    }
+
+    if let Ok(table) = PriorityTable::from_alphabet(input) {
+        let _ = part_one(&table, input);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use day_two::mapping::Mapping;
+use day_two::rules::Rules;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let classic = Rules::classic();
+
+    if let Ok(rules) = input.parse::<Rules>() {
+        let _ = Mapping::naive().score_guide(&rules, input);
+    }
+
+    if let Ok(mapping) = Mapping::from_str(&classic, input) {
+        let _ = mapping.score_guide(&classic, input);
+    }
+
+    let _ = Mapping::naive().score_guide(&classic, input);
+});